@@ -1,6 +1,6 @@
 use node_popi_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, PopiModuleConfig,
+	Signature, SudoConfig, SystemConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -59,6 +59,8 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Demo organization
+				demo_organization(),
 				true,
 			)
 		},
@@ -107,6 +109,8 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Demo organization
+				demo_organization(),
 				true,
 			)
 		},
@@ -124,12 +128,44 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
+/// A small organization to start from: Alice is the product owner of an ecommerce board and Bob
+/// one of its frontend developers, both already experts of their role.
+fn demo_organization() -> PopiModuleConfig {
+	let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+	let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+	// Experience types get their ids in registration order
+	let (product, frontend, backend, qa) = (0, 1, 2, 3);
+	let column = |name: &str, exp_type, min_level| (name.as_bytes().to_vec(), exp_type, min_level);
+
+	PopiModuleConfig {
+		experience_types: ["Product", "Frontend", "Backend", "QA"]
+			.iter()
+			.map(|name| name.as_bytes().to_vec())
+			.collect(),
+		boards: vec![(
+			alice.clone(),
+			b"ecommerce".to_vec(),
+			vec![
+				column("NEW", product, 0),
+				column("TODO", product, 1),
+				column("IN PROGRESS", frontend, 0),
+				column("CODE REVIEW", frontend, 1),
+				column("QA", qa, 0),
+				column("READY TO DEPLOY", backend, 0),
+				column("DONE", product, 1),
+			],
+		)],
+		experts: vec![(alice, product, 3), (bob, frontend, 3)],
+	}
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	wasm_binary: &[u8],
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	popi_module: PopiModuleConfig,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -152,5 +188,6 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		popi_module,
 	}
 }
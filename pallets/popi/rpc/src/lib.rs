@@ -0,0 +1,251 @@
+//! RPC interface for the popi pallet, exposing the `PopiApi` runtime API as `popi_*` methods.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_popi_runtime_api::PopiApi as PopiRuntimeApi;
+use pallet_popi_runtime_api::{
+	BoardId, ColumnIndex, EvidenceInfo, ExperienceInfo, ExperienceTypeId, InteractionPage,
+	LevelPage, TaskId,
+};
+
+#[rpc(client, server)]
+pub trait PopiApi<BlockHash, AccountId> {
+	/// The experience of `who` in `exp_type`, `null` if they never earned any
+	#[method(name = "popi_userExperience")]
+	fn user_experience(
+		&self,
+		who: AccountId,
+		exp_type: ExperienceTypeId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ExperienceInfo>>;
+
+	/// The experience of `who` in every experience type they earned some of
+	#[method(name = "popi_userProfile")]
+	fn user_profile(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ExperienceTypeId, ExperienceInfo)>>;
+
+	/// The experience required to level up from each level, starting from level 0
+	#[method(name = "popi_levelThresholds")]
+	fn level_thresholds(&self, at: Option<BlockHash>) -> RpcResult<Vec<u128>>;
+
+	/// Whether the given positive interaction has been recorded
+	#[method(name = "popi_interactionExists")]
+	fn interaction_exists(
+		&self,
+		approver: AccountId,
+		worker: AccountId,
+		board_id: BoardId,
+		task_id: TaskId,
+		src_state: ColumnIndex,
+		dst_state: ColumnIndex,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// The evidence attached to the given positive interaction, `null` if there is none
+	#[method(name = "popi_interactionEvidence")]
+	fn interaction_evidence(
+		&self,
+		approver: AccountId,
+		worker: AccountId,
+		board_id: BoardId,
+		task_id: TaskId,
+		src_state: ColumnIndex,
+		dst_state: ColumnIndex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<EvidenceInfo>>;
+
+	/// A page of at most `limit` interactions that happened on `board_id`. Pass the
+	/// `next_cursor` of a page as `cursor` to get the following one
+	#[method(name = "popi_interactionsByBoard")]
+	fn interactions_by_board(
+		&self,
+		board_id: BoardId,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<InteractionPage<AccountId>>;
+
+	/// A page of at most `limit` interactions on the work of `worker`. Pass the `next_cursor`
+	/// of a page as `cursor` to get the following one
+	#[method(name = "popi_interactionsByWorker")]
+	fn interactions_by_worker(
+		&self,
+		worker: AccountId,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<InteractionPage<AccountId>>;
+
+	/// A page of at most `limit` accounts with at least `min_level` in `exp_type`, by ascending
+	/// level. Pass the `next_cursor` of a page as `cursor` to get the following one
+	#[method(name = "popi_accountsByLevel")]
+	fn accounts_by_level(
+		&self,
+		exp_type: ExperienceTypeId,
+		min_level: u32,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<LevelPage<AccountId>>;
+}
+
+/// Provides the `popi_*` RPC methods, querying the runtime of `client`
+pub struct Popi<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Popi<C, Block> {
+	/// Creates a new instance of the popi RPC handler
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api
+pub enum Error {
+	/// The call to the runtime failed
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the popi runtime api",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId> PopiApiServer<<Block as BlockT>::Hash, AccountId> for Popi<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PopiRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Send + Sync + 'static,
+{
+	fn user_experience(
+		&self,
+		who: AccountId,
+		exp_type: ExperienceTypeId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ExperienceInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.user_experience(at, who, exp_type)
+			.map_err(runtime_error)
+	}
+
+	fn user_profile(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(ExperienceTypeId, ExperienceInfo)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().user_profile(at, who).map_err(runtime_error)
+	}
+
+	fn level_thresholds(&self, at: Option<Block::Hash>) -> RpcResult<Vec<u128>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().level_thresholds(at).map_err(runtime_error)
+	}
+
+	fn interaction_exists(
+		&self,
+		approver: AccountId,
+		worker: AccountId,
+		board_id: BoardId,
+		task_id: TaskId,
+		src_state: ColumnIndex,
+		dst_state: ColumnIndex,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.interaction_exists(at, approver, worker, board_id, task_id, src_state, dst_state)
+			.map_err(runtime_error)
+	}
+
+	fn interaction_evidence(
+		&self,
+		approver: AccountId,
+		worker: AccountId,
+		board_id: BoardId,
+		task_id: TaskId,
+		src_state: ColumnIndex,
+		dst_state: ColumnIndex,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<EvidenceInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.interaction_evidence(at, approver, worker, board_id, task_id, src_state, dst_state)
+			.map_err(runtime_error)
+	}
+
+	fn interactions_by_board(
+		&self,
+		board_id: BoardId,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<InteractionPage<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.interactions_by_board(at, board_id, cursor, limit)
+			.map_err(runtime_error)
+	}
+
+	fn interactions_by_worker(
+		&self,
+		worker: AccountId,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<InteractionPage<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.interactions_by_worker(at, worker, cursor, limit)
+			.map_err(runtime_error)
+	}
+
+	fn accounts_by_level(
+		&self,
+		exp_type: ExperienceTypeId,
+		min_level: u32,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<LevelPage<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.accounts_by_level(at, exp_type, min_level, cursor, limit)
+			.map_err(runtime_error)
+	}
+}
@@ -0,0 +1,72 @@
+//! Runtime API definition for the popi pallet, so that clients can read the experience of a user
+//! without decoding the raw storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_popi::{
+	BoardId, ColumnIndex, Evidence, EvidenceInfo, ExperienceInfo, ExperienceTypeId,
+	InteractionInfo, InteractionPage, LevelPage, TaskId,
+};
+
+sp_api::decl_runtime_apis! {
+	pub trait PopiApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// The experience of `who` in `exp_type`, `None` if they never earned any
+		fn user_experience(who: AccountId, exp_type: ExperienceTypeId) -> Option<ExperienceInfo>;
+
+		/// The experience of `who` in every experience type they earned some of
+		fn user_profile(who: AccountId) -> Vec<(ExperienceTypeId, ExperienceInfo)>;
+
+		/// The experience required to level up from each level, starting from level 0
+		fn level_thresholds() -> Vec<u128>;
+
+		/// Whether the positive interaction of `approver` on the work of `worker` moving the task
+		/// from `src_state` to `dst_state` has been recorded
+		fn interaction_exists(
+			approver: AccountId,
+			worker: AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			src_state: ColumnIndex,
+			dst_state: ColumnIndex,
+		) -> bool;
+
+		/// The evidence attached by the worker and the approver to a recorded interaction, `None`
+		/// if there is none
+		fn interaction_evidence(
+			approver: AccountId,
+			worker: AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			src_state: ColumnIndex,
+			dst_state: ColumnIndex,
+		) -> Option<EvidenceInfo>;
+
+		/// Up to `limit` interactions that happened on `board_id`, starting after `cursor`
+		fn interactions_by_board(
+			board_id: BoardId,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> InteractionPage<AccountId>;
+
+		/// Up to `limit` interactions on the work of `worker`, starting after `cursor`
+		fn interactions_by_worker(
+			worker: AccountId,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> InteractionPage<AccountId>;
+
+		/// Up to `limit` accounts with at least `min_level` in `exp_type` by ascending level,
+		/// starting after `cursor`
+		fn accounts_by_level(
+			exp_type: ExperienceTypeId,
+			min_level: u32,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> LevelPage<AccountId>;
+	}
+}
@@ -5,30 +5,644 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as popi;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{Currency, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::Zero;
+use sp_std::{vec, vec::Vec};
+
+/// The longest name a board, column or experience type may have
+fn max_name<T: Config>() -> Vec<u8> {
+	vec![b'a'; T::MaxNameLength::get() as usize]
+}
+
+/// Registers an active experience type straight into storage
+fn add_experience_type<T: Config>() -> ExperienceTypeId {
+	let exp_type = NextExperienceTypeId::<T>::get();
+	let info = ExperienceTypeInfo::<T> { name: max_name::<T>().try_into().unwrap(), active: true };
+	ExperienceTypes::<T>::insert(exp_type, info);
+	NextExperienceTypeId::<T>::put(exp_type + 1);
+	exp_type
+}
+
+/// `c` columns with the longest names, requiring `exp_type` at level zero
+fn columns<T: Config>(c: u32, exp_type: ExperienceTypeId) -> Vec<(Vec<u8>, ExperienceTypeId, u32)> {
+	(0..c).map(|_| (max_name::<T>(), exp_type, 0)).collect()
+}
+
+/// `e` evidence references of the largest kind
+fn evidence(e: u32) -> Vec<Evidence> {
+	let cid = vec![0u8; MaxCidLength::get() as usize];
+	(0..e).map(|_| Evidence::IpfsCid(cid.clone().try_into().unwrap())).collect()
+}
+
+/// Creates a board owned by `owner` with `MaxColumns` columns
+fn add_board<T: Config>(owner: T::AccountId) -> BoardId {
+	let exp_type = add_experience_type::<T>();
+	let board_id = NextBoardId::<T>::get();
+	Pallet::<T>::create_board(
+		RawOrigin::Signed(owner).into(),
+		max_name::<T>(),
+		columns::<T>(T::MaxColumns::get(), exp_type),
+	)
+	.unwrap();
+	board_id
+}
+
+/// Makes `amount` available to `who`, on top of the existential deposit
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+	T::Currency::make_free_balance_be(who, T::Currency::minimum_balance() + amount);
+}
+
+/// Bonds `amount` of the funds of `who`
+fn add_bond<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+	fund::<T>(who, amount);
+	Pallet::<T>::bond(RawOrigin::Signed(who.clone()).into(), amount).unwrap();
+}
+
+/// Fills the bond of `who` with as many locks as it can carry, all of them released by now, so
+/// they all have to be dropped
+fn add_released_locks<T: Config>(who: &T::AccountId) {
+	let now = frame_system::Pallet::<T>::block_number();
+	Bonds::<T>::mutate(who, |bond| {
+		bond.locks = vec![(Zero::zero(), now); T::MaxBondLocks::get() as usize].try_into().unwrap();
+	});
+}
+
+/// Records straight into storage an interaction moving task 0 of a new board to its second
+/// column, crediting the worker
+fn add_interaction<T: Config>() -> (InteractionIdentifier<T>, ExperienceTypeId) {
+	let approver: T::AccountId = account("approver", 0, 0);
+	let worker: T::AccountId = account("worker", 0, 0);
+	let board_id = add_board::<T>(account("owner", 0, 0));
+	let exp_type = Pallet::<T>::boards(board_id).unwrap().columns[1].exp_type;
+	let awarded = T::ExperiencePerInteraction::get();
+	// The approval locked a bond to slash
+	let bond = Pallet::<T>::required_bond(awarded).max(T::Currency::minimum_balance());
+	let upi = InteractionIdentifier::<T> {
+		approver: approver.clone(),
+		worker: worker.clone(),
+		board_id,
+		task_id: 0,
+		src_state: 0,
+		dst_state: 1,
+	};
+	let metadata = InteractionMetadata::<T> {
+		block: Some(frame_system::Pallet::<T>::block_number()),
+		moment: Some(Default::default()),
+		src_state: 0,
+		dst_state: 1,
+		exp_type: Some(exp_type),
+		awarded: Some(awarded),
+		bond,
+		evidence: None,
+	};
+	Pallet::<T>::store_interaction(upi.clone(), metadata).unwrap();
+	Pallet::<T>::add_user_experience(worker, exp_type, awarded).unwrap();
+	add_bond::<T>(&approver, bond);
+	(upi, exp_type)
+}
+
+/// Makes the parties of a dispute on `upi` and `jurors` more accounts experts of `exp_type`.
+/// The parties are visited by the draw and left out of the jury. They all reach the last level,
+/// so none decays below `JuryMinLevel` before the jury is drawn
+fn add_jurors<T: Config>(
+	challenger: &T::AccountId,
+	upi: &InteractionIdentifier<T>,
+	exp_type: ExperienceTypeId,
+	jurors: u32,
+) {
+	let experience = experience_for_level::<T::LevelCurve>(T::MaxLevel::get(), T::MaxLevel::get());
+	let jurors = (0..jurors).map(|i| account("juror", i, 0));
+	for who in [challenger.clone(), upi.approver.clone(), upi.worker.clone()]
+		.into_iter()
+		.chain(jurors)
+	{
+		Pallet::<T>::add_user_experience(who, exp_type, experience).unwrap();
+	}
+}
+
+/// Challenges a new interaction, with `jurors` experts besides the parties to draw the jury
+/// among. Returns the id of the dispute, once its jury can be drawn
+fn add_challenge<T: Config>(jurors: u32) -> DisputeId {
+	let challenger: T::AccountId = account("challenger", 0, 0);
+	let (upi, exp_type) = add_interaction::<T>();
+	add_jurors::<T>(&challenger, &upi, exp_type, jurors);
+	fund::<T>(&challenger, T::ChallengeDeposit::get());
+	let dispute_id = NextDisputeId::<T>::get();
+	Pallet::<T>::challenge_interaction(
+		RawOrigin::Signed(challenger).into(),
+		upi.approver,
+		upi.worker,
+		upi.board_id,
+		upi.task_id,
+		upi.src_state,
+		upi.dst_state,
+	)
+	.unwrap();
+	// The randomness of the last block the jury may be drawn in was not determinable when the
+	// interaction was challenged
+	let deadline = Disputes::<T>::get(dispute_id).unwrap().deadline;
+	frame_system::Pallet::<T>::set_block_number(deadline);
+	dispute_id
+}
+
+/// Challenges a new interaction and draws its jury, returns the id of the dispute
+fn add_dispute<T: Config>() -> DisputeId {
+	let dispute_id = add_challenge::<T>(T::JurySize::get());
+	let candidates = T::JurySize::get() + 3;
+	Pallet::<T>::draw_jury(
+		RawOrigin::Signed(account("drawer", 0, 0)).into(),
+		dispute_id,
+		candidates,
+	)
+	.unwrap();
+	dispute_id
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
+	fn interact(e: Linear<0, { T::MaxEvidence::get() }>) {
+		let worker: T::AccountId = account("worker", 0, 0);
+		let approver: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let board_id = add_board::<T>(owner.clone());
+		// The task reaches the last column, so its bounty has to be paid
+		let last = T::MaxColumns::get() as ColumnIndex - 1;
+		TaskStates::<T>::insert(board_id, 0, last - 1);
+		ColumnTasks::<T>::insert(board_id, last - 1, 1);
+		let exp_type = Pallet::<T>::boards(board_id).unwrap().columns[last as usize].exp_type;
+		// The worker is about to reach the last level, so all the curve has to be walked
+		let experience =
+			experience_for_level::<T::LevelCurve>(T::MaxLevel::get(), T::MaxLevel::get());
+		Pallet::<T>::add_user_experience(
+			worker.clone(),
+			exp_type,
+			experience.saturating_sub(T::ExperiencePerInteraction::get()),
+		)
+		.unwrap();
+		// Both histories are full, so the oldest entries have to be evicted
+		let entry = HistoryEntry::<T> {
+			counterpart: approver.clone(),
+			board_id,
+			task_id: 1,
+			dst_state: last,
+			block: Default::default(),
+			moment: Default::default(),
+		};
+		let history: BoundedVec<_, _> =
+			vec![entry; T::MaxPositiveUserInteractions::get() as usize].try_into().unwrap();
+		WorkerHistory::<T>::insert(&worker, history.clone());
+		ApproverHistory::<T>::insert(&approver, history);
+		// The pair approved each other as often as tracked, so the oldest approval is evicted
+		let now = frame_system::Pallet::<T>::block_number();
+		let blocks: BoundedVec<_, _> =
+			vec![now; T::MaxPairRepetitions::get() as usize].try_into().unwrap();
+		PairInteractions::<T>::insert(&approver, &worker, blocks);
+		let granted = T::AwardWeighting::weigh(T::ExperiencePerInteraction::get(), 0);
+		add_bond::<T>(&approver, Pallet::<T>::required_bond(granted));
+		add_released_locks::<T>(&approver);
+		// Every earlier column was worked on by someone new, who gets a share of the bounty
+		let contributions: BoundedVec<_, _> = (0..last)
+			.map(|i| Contribution {
+				dst_state: i,
+				worker: account("contributor", i, 0),
+				granted: T::ExperiencePerInteraction::get(),
+				awarded: T::ExperiencePerInteraction::get(),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		TaskContributions::<T>::insert(board_id, 0, contributions);
+		let amount = T::Currency::minimum_balance() * 100u32.into() * last.into();
+		fund::<T>(&owner, amount);
+		Pallet::<T>::fund_bounty(RawOrigin::Signed(owner).into(), board_id, 0, amount).unwrap();
+		// The worker attached the most evidence, so the largest record has to be hashed
+		Pallet::<T>::mark_ready(
+			RawOrigin::Signed(worker.clone()).into(),
+			board_id,
+			0,
+			evidence(T::MaxEvidence::get()),
+		)
+		.unwrap();
+		Pallet::<T>::pull(RawOrigin::Signed(approver.clone()).into(), board_id, 0).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(approver), worker.clone(), board_id, 0, last, evidence(e));
+
+		assert_eq!(TaskStates::<T>::get(board_id, 0), last);
+		assert!(!Bounties::<T>::contains_key(board_id, 0));
+		assert_eq!(WorkerHistory::<T>::get(worker).last().unwrap().task_id, 0);
+	}
+
+	#[benchmark]
+	fn create_board(c: Linear<2, { T::MaxColumns::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let exp_type = add_experience_type::<T>();
+		let board_id = NextBoardId::<T>::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), max_name::<T>(), columns::<T>(c, exp_type));
+
+		assert_eq!(Boards::<T>::get(board_id).unwrap().columns.len(), c as usize);
+	}
+
+	#[benchmark]
+	fn update_board(c: Linear<2, { T::MaxColumns::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		// No task is in the columns of the board, so the update may drop all but two
+		let board_id = add_board::<T>(caller.clone());
+		let exp_type = add_experience_type::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), board_id, max_name::<T>(), columns::<T>(c, exp_type));
+
+		assert_eq!(Boards::<T>::get(board_id).unwrap().columns.len(), c as usize);
+	}
+
+	#[benchmark]
+	fn archive_board() {
+		let caller: T::AccountId = whitelisted_caller();
+		let board_id = add_board::<T>(caller.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), board_id);
+
+		assert!(Boards::<T>::get(board_id).unwrap().archived);
+	}
+
+	#[benchmark]
+	fn mark_ready(e: Linear<0, { T::MaxEvidence::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let board_id = add_board::<T>(account("owner", 0, 0));
+		let assignment = Assignment { worker: caller.clone(), status: TaskStatus::InProgress };
+		Assignments::<T>::insert(board_id, 0, assignment);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), board_id, 0, evidence(e));
+
+		assert_eq!(Assignments::<T>::get(board_id, 0).unwrap().status, TaskStatus::ReadyToBePulled);
+	}
+
+	#[benchmark]
+	fn pull() {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, 0);
+		let board_id = add_board::<T>(account("owner", 0, 0));
+		Pallet::<T>::mark_ready(RawOrigin::Signed(worker).into(), board_id, 0, vec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), board_id, 0);
+
+		assert_eq!(
+			Assignments::<T>::get(board_id, 0).unwrap().status,
+			TaskStatus::Pulled { by: caller }
+		);
+	}
+
+	#[benchmark]
+	fn unpull() {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, 0);
+		let board_id = add_board::<T>(account("owner", 0, 0));
+		Pallet::<T>::mark_ready(RawOrigin::Signed(worker).into(), board_id, 0, vec![]).unwrap();
+		Pallet::<T>::pull(RawOrigin::Signed(caller.clone()).into(), board_id, 0).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), board_id, 0);
+
+		assert_eq!(Assignments::<T>::get(board_id, 0).unwrap().status, TaskStatus::ReadyToBePulled);
+	}
+
+	// Evicting a worker who gave evidence writes more than releasing a pulled task
+	#[benchmark]
+	fn release_task() {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker: T::AccountId = account("worker", 0, 0);
+		let board_id = add_board::<T>(owner.clone());
+		let evidence = evidence(T::MaxEvidence::get());
+		Pallet::<T>::mark_ready(RawOrigin::Signed(worker).into(), board_id, 0, evidence).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), board_id, 0);
+
+		assert!(Assignments::<T>::get(board_id, 0).is_none());
+		assert!(PendingEvidence::<T>::get(board_id, 0).is_none());
+	}
+
+	#[benchmark]
+	fn seed_expert(l: Linear<0, { T::MaxLevel::get() }>) -> Result<(), BenchmarkError> {
+		let origin = T::ExperienceTypeAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("expert", 0, 0);
+		let exp_type = add_experience_type::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone(), exp_type, l);
+
+		assert_eq!(ExperienceStorage::<T>::get((who, exp_type)).unwrap().level, l);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_experience_type() -> Result<(), BenchmarkError> {
+		let origin = T::ExperienceTypeAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let exp_type = NextExperienceTypeId::<T>::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, max_name::<T>());
+
+		assert!(ExperienceTypes::<T>::contains_key(exp_type));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_experience_type() -> Result<(), BenchmarkError> {
+		let origin = T::ExperienceTypeAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let exp_type = add_experience_type::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, exp_type, max_name::<T>(), false);
+
+		assert!(!ExperienceTypes::<T>::get(exp_type).unwrap().active);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn sweep_decay(n: Linear<0, 100>) {
+		let exp_type = add_experience_type::<T>();
+		let experience =
+			experience_for_level::<T::LevelCurve>(T::MaxLevel::get(), T::MaxLevel::get());
+		// Every experience lost a level since it was gained, in the first block, so all are stored
+		for i in 0..n {
+			let who: T::AccountId = account("expert", i, 0);
+			ExperienceStorage::<T>::insert(
+				(&who, exp_type),
+				UserExperience::<T> {
+					account_id: who.clone(),
+					experience,
+					level: T::MaxLevel::get(),
+					experience_to_next_level: 0,
+				},
+			);
+			Pallet::<T>::index_level(&who, exp_type, None, T::MaxLevel::get());
+			ExperienceDecayedAt::<T>::insert(
+				(who, exp_type),
+				(BlockNumberFor::<T>::from(1u32), experience),
+			);
+		}
+		frame_system::Pallet::<T>::set_block_number(u32::MAX.into());
+
+		#[block]
+		{
+			Pallet::<T>::sweep_decay(n);
+		}
+
+		if n > 0 && T::ExperienceHalfLife::get().is_some() {
+			let who: T::AccountId = account("expert", 0, 0);
+			assert!(
+				ExperienceStorage::<T>::get((who, exp_type)).unwrap().level < T::MaxLevel::get()
+			);
+		}
+	}
+
+	#[benchmark]
+	fn sweep_pairs(n: Linear<0, 100>) {
+		let worker: T::AccountId = account("worker", 0, 0);
+		// Every pair approved in the first block, which is outside of the window by now
+		for i in 0..n {
+			let approver: T::AccountId = account("approver", i, 0);
+			let blocks = vec![BlockNumberFor::<T>::from(1u32)].try_into().unwrap();
+			PairInteractions::<T>::insert(approver, &worker, blocks);
+		}
+		frame_system::Pallet::<T>::set_block_number(u32::MAX.into());
+
+		#[block]
+		{
+			Pallet::<T>::sweep_pairs(n);
+		}
+
+		assert_eq!(PairInteractions::<T>::iter().count(), 0);
+	}
+
+	#[benchmark]
+	fn sweep_epochs(n: Linear<0, 100>) {
+		// The rewards of the first epoch expired, its contributors are left to prune. Moving on
+		// to the next epoch costs as much as pruning two of them
+		for i in 0..n {
+			let who: T::AccountId = account("contributor", i, 0);
+			EpochExperience::<T>::insert(0, who, T::ExperiencePerInteraction::get());
+		}
+		CurrentEpoch::<T>::put(T::RewardClaimEpochs::get() + 1);
+
+		#[block]
+		{
+			Pallet::<T>::sweep_epochs(n);
+		}
+
+		assert_eq!(EpochExperience::<T>::iter_prefix(0).count(), 0);
+	}
+
+	#[benchmark]
+	fn challenge_interaction() {
+		let challenger: T::AccountId = whitelisted_caller();
+		let (upi, _) = add_interaction::<T>();
+		fund::<T>(&challenger, T::ChallengeDeposit::get());
+		let dispute_id = NextDisputeId::<T>::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(challenger),
+			upi.approver.clone(),
+			upi.worker.clone(),
+			upi.board_id,
+			upi.task_id,
+			upi.src_state,
+			upi.dst_state,
+		);
+
+		assert!(Disputes::<T>::get(dispute_id).unwrap().jury.is_empty());
+	}
+
+	#[benchmark]
+	fn draw_jury(c: Linear<{ T::JurySize::get() + 3 }, 1_000>) {
+		let caller: T::AccountId = whitelisted_caller();
+		// The three parties are visited as well, then left out
+		let dispute_id = add_challenge::<T>(c - 3);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), dispute_id, c);
+
+		assert_eq!(Disputes::<T>::get(dispute_id).unwrap().jury.len(), T::JurySize::get() as usize);
+	}
+
+	#[benchmark]
+	fn vote() {
+		let dispute_id = add_dispute::<T>();
+		let mut jury = Disputes::<T>::get(dispute_id).unwrap().jury.into_inner();
+		// The last vote closes the dispute, finding the approver guilty
+		let juror = jury.pop().unwrap();
+		for other in jury {
+			Pallet::<T>::vote(RawOrigin::Signed(other).into(), dispute_id, true).unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(juror), dispute_id, true);
+
+		assert!(!Disputes::<T>::contains_key(dispute_id));
+	}
+
+	#[benchmark]
+	fn resolve_dispute() {
+		let caller: T::AccountId = whitelisted_caller();
+		let dispute_id = add_dispute::<T>();
+		let dispute = Disputes::<T>::get(dispute_id).unwrap();
+		// One juror did not vote, the others found the approver guilty
+		for juror in dispute.jury.into_iter().skip(1) {
+			Pallet::<T>::vote(RawOrigin::Signed(juror).into(), dispute_id, true).unwrap();
+		}
+		frame_system::Pallet::<T>::set_block_number(dispute.deadline + 1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), dispute_id);
+
+		assert!(!Disputes::<T>::contains_key(dispute_id));
+	}
+
+	#[benchmark]
+	fn bond() {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		fund::<T>(&caller, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), amount);
+
+		assert_eq!(Bonds::<T>::get(caller).active, amount);
+	}
+
+	#[benchmark]
+	fn unbond() {
 		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		add_bond::<T>(&caller, amount);
+		add_released_locks::<T>(&caller);
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		_(RawOrigin::Signed(caller.clone()), amount);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Bonds::<T>::get(caller).unbonding, amount);
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn withdraw_unbonded() {
 		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		add_bond::<T>(&caller, amount);
+		Pallet::<T>::unbond(RawOrigin::Signed(caller.clone()).into(), amount).unwrap();
+		let unlock_at = Bonds::<T>::get(&caller).unlock_at;
+		frame_system::Pallet::<T>::set_block_number(unlock_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Bonds::<T>::contains_key(caller));
+	}
+
+	#[benchmark]
+	fn fund_bounty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let board_id = add_board::<T>(caller.clone());
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		fund::<T>(&caller, amount * 2u32.into());
+		// The bounty is topped up
+		Pallet::<T>::fund_bounty(RawOrigin::Signed(caller.clone()).into(), board_id, 0, amount)
+			.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), board_id, 0, amount);
+
+		assert_eq!(Bounties::<T>::get(board_id, 0).unwrap().amount, amount * 2u32.into());
+	}
+
+	#[benchmark]
+	fn cancel_bounty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let board_id = add_board::<T>(caller.clone());
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		fund::<T>(&caller, amount);
+		Pallet::<T>::fund_bounty(RawOrigin::Signed(caller.clone()).into(), board_id, 0, amount)
+			.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), board_id, 0);
+
+		assert!(!Bounties::<T>::contains_key(board_id, 0));
+	}
+
+	#[benchmark]
+	fn close_epoch() {
+		// The rewards of the first epoch expire as this one closes, and go back to the pot
+		let epoch = T::RewardClaimEpochs::get();
+		CurrentEpoch::<T>::put(epoch);
+		let unclaimed = T::Currency::minimum_balance();
+		EpochRewards::<T>::insert(0, EpochReward { total: unclaimed, unclaimed });
+		let summary =
+			EpochSummary { experience: T::ExperiencePerInteraction::get(), contributors: 1 };
+		EpochTotals::<T>::insert(epoch, summary);
+		// The epoch had contributors, so the pot is handed over to them
+		let funder: T::AccountId = account("funder", 0, 0);
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		fund::<T>(&funder, amount);
+		Pallet::<T>::fund_rewards(RawOrigin::Signed(funder).into(), amount).unwrap();
+		let now = EpochStart::<T>::get() + T::EpochLength::get();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(now);
+		}
+
+		assert_eq!(CurrentEpoch::<T>::get(), epoch + 1);
+		assert_eq!(EpochStart::<T>::get(), now);
+		assert_eq!(EpochRewards::<T>::get(epoch).total, amount + T::InflationPerEpoch::get());
+		assert!(EpochRewards::<T>::get(0).unclaimed.is_zero());
+	}
+
+	#[benchmark]
+	fn fund_rewards() {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		fund::<T>(&caller, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), amount);
+
+		assert_eq!(RewardPot::<T>::get(), amount);
+	}
+
+	#[benchmark]
+	fn claim_rewards() {
+		let caller: T::AccountId = whitelisted_caller();
+		let epoch = CurrentEpoch::<T>::get();
+		let experience = T::ExperiencePerInteraction::get();
+		EpochExperience::<T>::insert(epoch, &caller, experience);
+		EpochTotals::<T>::insert(epoch, EpochSummary { experience, contributors: 1 });
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		fund::<T>(&caller, amount);
+		Pallet::<T>::fund_rewards(RawOrigin::Signed(caller.clone()).into(), amount).unwrap();
+		Pallet::<T>::close_epoch(EpochStart::<T>::get() + T::EpochLength::get());
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(RawOrigin::Signed(caller.clone()), epoch);
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert!(RewardsClaimed::<T>::contains_key(epoch, caller));
 	}
 
 	impl_benchmark_test_suite!(popi, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,80 @@
+//! Level curves used to turn accumulated experience into a level.
+//!
+//! A curve only answers one question: how much experience is needed to advance from `level` to
+//! `level + 1`. Every implementation uses saturating arithmetic, so a badly tuned curve makes the
+//! next level unreachable instead of overflowing.
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+
+/// Defines the amount of experience required to level up
+pub trait LevelCurve {
+	/// Experience required to advance from `level` to `level + 1`
+	fn experience_for_next_level(level: u32) -> u128;
+}
+
+/// `Base * Multiplier ^ (Difficulty * level)`
+///
+/// With `Base = 100`, `Multiplier = 2` and `Difficulty = 1` the thresholds are
+/// 100, 200, 400, 800, 1600, ...
+pub struct ExponentialCurve<Base, Multiplier, Difficulty>(
+	PhantomData<(Base, Multiplier, Difficulty)>,
+);
+impl<Base, Multiplier, Difficulty> LevelCurve for ExponentialCurve<Base, Multiplier, Difficulty>
+where
+	Base: Get<u128>,
+	Multiplier: Get<u32>,
+	Difficulty: Get<u32>,
+{
+	fn experience_for_next_level(level: u32) -> u128 {
+		let exponent = Difficulty::get().saturating_mul(level);
+		Base::get().saturating_mul((Multiplier::get() as u128).saturating_pow(exponent))
+	}
+}
+
+/// `Base * (level + 1) ^ Exponent`
+///
+/// With `Base = 100` and `Exponent = 2` the thresholds are 100, 400, 900, 1600, ...
+pub struct PolynomialCurve<Base, Exponent>(PhantomData<(Base, Exponent)>);
+impl<Base, Exponent> LevelCurve for PolynomialCurve<Base, Exponent>
+where
+	Base: Get<u128>,
+	Exponent: Get<u32>,
+{
+	fn experience_for_next_level(level: u32) -> u128 {
+		let step = (level as u128).saturating_add(1);
+		Base::get().saturating_mul(step.saturating_pow(Exponent::get()))
+	}
+}
+
+/// `Base * (level + 1)`
+///
+/// With `Base = 100` the thresholds are 100, 200, 300, 400, ...
+pub struct LinearCurve<Base>(PhantomData<Base>);
+impl<Base: Get<u128>> LevelCurve for LinearCurve<Base> {
+	fn experience_for_next_level(level: u32) -> u128 {
+		Base::get().saturating_mul((level as u128).saturating_add(1))
+	}
+}
+
+/// Maps a total amount of experience to `(level, experience_to_next_level)`.
+///
+/// The level never exceeds `max_level`; once it is reached the experience to the next level is
+/// zero. A threshold of zero is treated as the last reachable level.
+pub fn level_from_experience<C: LevelCurve>(experience: u128, max_level: u32) -> (u32, u128) {
+	let mut remaining = experience;
+	for level in 0..max_level {
+		let required = C::experience_for_next_level(level);
+		if remaining < required || required == 0 {
+			return (level, required.saturating_sub(remaining))
+		}
+		remaining -= required;
+	}
+	(max_level, 0)
+}
+
+/// Total amount of experience needed to reach `level` starting from zero, `level` being capped
+/// at `max_level`
+pub fn experience_for_level<C: LevelCurve>(level: u32, max_level: u32) -> u128 {
+	(0..level.min(max_level))
+		.fold(0u128, |total, l| total.saturating_add(C::experience_for_next_level(l)))
+}
@@ -13,22 +13,46 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod curve;
+pub mod migrations;
+pub mod weighting;
 pub mod weights;
+pub use curve::*;
+pub use weighting::*;
 pub use weights::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::{DispatchResult, *};
+	use frame_support::{
+		pallet_prelude::{DispatchResult, *},
+		storage::{PrefixIterator, StoragePrefixedMap},
+		traits::{
+			Currency, ExistenceRequirement::KeepAlive, Imbalance, OnUnbalanced, Randomness,
+			ReservableCurrency, Time,
+		},
+		PalletId, StorageHasher,
+	};
 	use frame_system::pallet_prelude::*;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_runtime::{
+		traits::{AccountIdConversion, Hash, Saturating, Zero},
+		Perbill, SaturatedConversion,
+	};
+	use sp_std::vec::Vec;
+
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	///
-	/// For additional information on BaseExperience, LevelDifficulty, and DifficultyMultiplier,
-	/// check `fn calculate_exp_to_next_level`.
+	/// For additional information on how levels are computed, check the `curve` module, and
+	/// for how approvals are weighted, the `weighting` module.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
@@ -36,49 +60,302 @@ pub mod pallet {
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 
+		/// The curve defining the amount of experience required to level up.
+		/// i.e. `ExponentialCurve<ConstU128<100>, ConstU32<2>, ConstU32<1>>`:
+		/// level 1: 100
+		/// level 2: 200
+		/// level 3: 400
+		/// level 4: 800
+		/// level 5: 1600
+		type LevelCurve: LevelCurve;
+
+		#[pallet::constant]
+		/// The highest level a user can reach for an experience type
+		type MaxLevel: Get<u32>;
+
+		#[pallet::constant]
+		/// The base amount of experience credited to the worker for every positive interaction
+		type ExperiencePerInteraction: Get<u128>;
+
+		/// How much of `ExperiencePerInteraction` an approval is worth depending on the level of
+		/// the approver in the experience type of the column.
+		/// i.e. `LinearWeighting<Floor, ConstU32<4>>` with a `Floor` of 20%: a fresh account
+		/// credits 2 out of 10, an approver at level 4 or above the whole 10
+		type AwardWeighting: AwardWeighting;
+
+		/// How much of the weighted award is left when the same approver already approved the
+		/// same worker within `PairWindow`, i.e. `HalvingDecay`
+		type RepetitionDecay: RepetitionDecay;
+
+		#[pallet::constant]
+		/// Number of blocks an approval keeps counting as a repetition for the same pair
+		type PairWindow: Get<Self::BlockNumber>;
+
+		#[pallet::constant]
+		/// Maximum number of approvals tracked per approver and worker pair within `PairWindow`.
+		/// Repetitions beyond it are counted as this maximum
+		type MaxPairRepetitions: Get<u32>;
+
+		#[pallet::constant]
+		/// Maximum length of a board or column name
+		type MaxNameLength: Get<u32>;
+
+		#[pallet::constant]
+		/// Maximum number of columns of a board
+		type MaxColumns: Get<u32>;
+
+		/// The origin allowed to register experience types, to change them and to seed their
+		/// experts, i.e. root or a council majority
+		type ExperienceTypeAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		#[pallet::constant]
+		/// Maximum number of historical positive interactions kept per account, as worker and as
+		/// approver. Once reached, the oldest entry is evicted to make room for the newest one
+		type MaxPositiveUserInteractions: Get<u32>;
+
+		/// Source of the timestamp recorded with every interaction, i.e. `pallet_timestamp`
+		type Time: Time;
+
+		#[pallet::constant]
+		/// Maximum number of interactions returned by a single page of the runtime API
+		type MaxPageSize: Get<u32>;
+
+		#[pallet::constant]
+		/// Maximum number of evidence references attached to `mark_ready` or `interact`
+		type MaxEvidence: Get<u32>;
+
+		#[pallet::constant]
+		/// Number of blocks after which an inactive user has lost half of their experience,
+		/// `None` to never decay experience
+		type ExperienceHalfLife: Get<Option<Self::BlockNumber>>;
+
+		/// The currency approver bonds and challenge deposits are reserved in
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		#[pallet::constant]
+		/// Bond an approver must have for every point of experience their approval grants
+		/// before any repetition decay, i.e. with a `BondPerExperience` of 5 an approver whose
+		/// approvals are worth 10 experience needs a bond of 50
+		type BondPerExperience: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// Number of blocks an unbonded amount stays reserved, and slashable, before it can be
+		/// withdrawn. It should exceed `VotingPeriod` so that unbonding does not dodge a dispute
+		type UnbondingPeriod: Get<Self::BlockNumber>;
+
+		/// Source of randomness used to draw the jury of a dispute. The jury is only drawn with
+		/// an output that was not determinable yet when the interaction was challenged, so the
+		/// challenger can not time the challenge to choose the jurors. Block authors can still
+		/// bias `pallet_insecure_randomness_collective_flip`, production runtimes need a secure
+		/// source such as the BABE VRF output
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Handler for the funds slashed from guilty approvers and from challengers whose
+		/// challenge was dismissed, i.e. the treasury
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// Deposit reserved from whoever challenges an interaction, returned unless the jury
+		/// finds the approver innocent
+		type ChallengeDeposit: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// Number of blocks after an approval during which it can be challenged. The bond backing
+		/// the approval stays locked until a challenge could be resolved, i.e. for
+		/// `ChallengePeriod + VotingPeriod` blocks
+		type ChallengePeriod: Get<Self::BlockNumber>;
+
+		#[pallet::constant]
+		/// Maximum number of locks an approver's bond may carry at once. Every block an
+		/// approver approves in adds one, released once its approvals can no longer be
+		/// challenged
+		type MaxBondLocks: Get<u32>;
+
+		#[pallet::constant]
+		/// Number of jurors drawn to judge a challenged interaction
+		type JurySize: Get<u32>;
+
+		#[pallet::constant]
+		/// Minimum level in the experience type credited by a challenged interaction to be
+		/// drawn as a juror
+		type JuryMinLevel: Get<u32>;
+
+		#[pallet::constant]
+		/// Number of blocks the jury has to be drawn and to vote once an interaction is
+		/// challenged. It has to exceed the blocks `Randomness` takes to become unpredictable
+		type VotingPeriod: Get<Self::BlockNumber>;
+
 		#[pallet::constant]
-		/// This is the amount of experience that a user needs to level up the first time
-		/// In addition, this will impact the amount of experience required to level up in the
-		/// future
-		type BaseExperience: Get<u128>;
+		/// Id of the pallet, the accounts holding the task bounties and the rewards of the
+		/// contributors are derived from it
+		type PalletId: Get<PalletId>;
 
 		#[pallet::constant]
-		/// Represents the overall difficulty of leveling up
-		type LevelDifficulty: Get<u32>;
+		/// Number of blocks of an epoch, the accounting period the experience earned through
+		/// interactions is tallied by. Zero never closes the first epoch
+		type EpochLength: Get<Self::BlockNumber>;
 
 		#[pallet::constant]
-		/// The multiplier for the amount of experience required to level up
-		type DifficultyMultiplier: Get<u32>;
+		/// Most experience an account may earn through interactions within an epoch, approvals
+		/// beyond it credit nothing. `None` to not cap it
+		type MaxExperiencePerEpoch: Get<Option<u128>>;
 
-		// #[pallet::constant]
-		// /// Maximum number of historical positive interactions per account
-		// type MaxPositiveUserInteractions: Get<u32>;
+		#[pallet::constant]
+		/// Minted into the reward pot every time an epoch closes, on top of what `fund_rewards`
+		/// brought in
+		type InflationPerEpoch: Get<BalanceOf<Self>>;
 
-		/*
-		level 1: 100
-		level 2: 200
-		level 3: 400
-		level 4: 800
-		level 5: 1600
-		BaseExperience * DifficultyMultiplier ^ (LevelDifficulty * (level - 1))
-		 */
+		#[pallet::constant]
+		/// Number of epochs the contributors of a closed epoch have to claim their rewards, what
+		/// is left unclaimed then goes back to the reward pot. At least 1, the rewards of an
+		/// epoch can only be claimed once it closed
+		type RewardClaimEpochs: Get<EpochId>;
 	}
 
 	// The pallet's runtime storage items.
 	// https://docs.substrate.io/main-docs/build/runtime-storage/
-	#[pallet::storage]
-	#[pallet::getter(fn something)]
-	// Learn more about declaring storage items:
-	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
-
 	/// A positive interaction consists on a task state transition
 	/// determined by a source AccountId on the work done by another dest accountId
+	//#[pallet::getter(fn positive_interaction_getter)]
+	#[pallet::storage]
+	pub type Interaction<T: Config> =
+		StorageMap<_, Blake2_128Concat, InteractionIdentifier<T>, InteractionMetadata<T>>;
+
+	/// The evidence of the work the worker of a task attached when marking it ready, until the
+	/// interaction moving the task forward is recorded
+	///
+	/// TWOX-NOTE: Safe for the board ids, which are increasing integers. The task ids are chosen
+	/// by the users, so they are hashed.
+	#[pallet::storage]
+	pub type PendingEvidence<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BoardId, Blake2_128Concat, TaskId, EvidenceList<T>>;
+
+	/// The funds approvers reserved to back their approvals
+	#[pallet::storage]
+	pub type Bonds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Bond<T>, ValueQuery>;
+
+	/// The bounties funded for tasks, held by the bounty account until the task reaches the
+	/// last column of its board
+	///
+	/// TWOX-NOTE: Safe for the board ids, which are increasing integers. The task ids are chosen
+	/// by the users, so they are hashed.
+	#[pallet::storage]
+	pub type Bounties<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BoardId, Blake2_128Concat, TaskId, Bounty<T>>;
+
+	/// The approved work on a task that did not reach the last column yet, a bounty is split
+	/// by it
+	///
+	/// TWOX-NOTE: Safe for the board ids, which are increasing integers. The task ids are chosen
+	/// by the users, so they are hashed.
+	#[pallet::storage]
+	pub type TaskContributions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BoardId,
+		Blake2_128Concat,
+		TaskId,
+		BoundedVec<Contribution<T>, T::MaxColumns>,
+		ValueQuery,
+	>;
+
+	/// The epoch the experience earned through interactions is currently tallied in
+	#[pallet::storage]
+	#[pallet::getter(fn current_epoch)]
+	pub type CurrentEpoch<T> = StorageValue<_, EpochId, ValueQuery>;
+
+	/// The block the current epoch started at
+	#[pallet::storage]
+	pub type EpochStart<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The experience every account earned through interactions within an epoch, over all
+	/// experience types. The entries of closed epochs are left as they were when they closed,
+	/// until their rewards expire and `on_idle` prunes them
 	///
 	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
-	//#[pallet::getter(fn positive_interaction_getter)]
 	#[pallet::storage]
-	pub type Interaction<T: Config> = StorageMap<_, Twox64Concat, InteractionIdentifier<T>, ()>;
+	pub type EpochExperience<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EpochId,
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// The experience earned within an epoch and the number of accounts who earned it
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	pub type EpochTotals<T> = StorageMap<_, Twox64Concat, EpochId, EpochSummary, ValueQuery>;
+
+	/// The funds waiting in the reward account to be shared among the contributors of the
+	/// current epoch. They roll over to the next epoch if nobody earned any experience
+	#[pallet::storage]
+	#[pallet::getter(fn reward_pot)]
+	pub type RewardPot<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The rewards shared among the contributors of a closed epoch, in proportion to the
+	/// experience they earned within it, and what is left of them to claim
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	pub type EpochRewards<T: Config> =
+		StorageMap<_, Twox64Concat, EpochId, EpochReward<T>, ValueQuery>;
+
+	/// The accounts who claimed their share of the rewards of an epoch, pruned by `on_idle`
+	/// once the rewards expired
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	pub type RewardsClaimed<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EpochId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The oldest epoch whose `EpochExperience` and `RewardsClaimed` entries may not have been
+	/// pruned yet
+	#[pallet::storage]
+	pub type EpochSweepCursor<T> = StorageValue<_, EpochId, ValueQuery>;
+
+	/// The open disputes on challenged interactions
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	pub type Disputes<T: Config> = StorageMap<_, Twox64Concat, DisputeId, Dispute<T>>;
+
+	/// The id the next dispute will get
+	#[pallet::storage]
+	pub type NextDisputeId<T> = StorageValue<_, DisputeId, ValueQuery>;
+
+	/// Where the challenged interactions stand. An interaction may only be challenged again if
+	/// its jury did not vote
+	#[pallet::storage]
+	pub type DisputedInteractions<T: Config> =
+		StorageMap<_, Blake2_128Concat, InteractionIdentifier<T>, DisputeStatus>;
+
+	/// The evidence backing a recorded interaction, its hash is kept in `InteractionMetadata`
+	#[pallet::storage]
+	pub type InteractionEvidence<T: Config> =
+		StorageMap<_, Blake2_128Concat, InteractionIdentifier<T>, EvidenceRecord<T>>;
+
+	/// Index of the interactions of `Interaction` by the board they happened on
+	///
+	/// TWOX-NOTE: Safe for the board ids, which are increasing integers. The interactions are
+	/// hashed, their accounts and task ids are chosen by the users.
+	#[pallet::storage]
+	pub type InteractionsByBoard<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BoardId, Blake2_128Concat, InteractionIdentifier<T>, ()>;
+
+	/// Index of the interactions of `Interaction` by their worker
+	#[pallet::storage]
+	pub type InteractionsByWorker<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		InteractionIdentifier<T>,
+		(),
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn storage_getter)]
@@ -88,31 +365,317 @@ pub mod pallet {
 		(
 			// This is the user's account id
 			T::AccountId,
-			// This is the type of experience that the user has
-			ExperienceType,
+			// This is the type of experience that the user has, see `ExperienceTypes`
+			ExperienceTypeId,
 		),
-		// This is the user's experience struct, specific to the exact "ExperienceType"
+		// This is the user's experience struct, specific to the exact experience type
 		UserExperience<T>,
 	>;
 
+	/// Index of `ExperienceStorage` by experience type and level, to find the experts of a role.
+	/// The level is encoded big endian so that the accounts of an experience type iterate by
+	/// ascending level, `accounts_by_level` relies on it.
+	///
+	/// IDENTITY-NOTE: Safe, the level is bounded by `MaxLevel`. The accounts are hashed so that
+	/// nobody can choose where they sit within a level.
+	#[pallet::storage]
+	pub type AccountsByLevel<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, ExperienceTypeId>,
+			NMapKey<Identity, [u8; 4]>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		(),
+	>;
+
+	/// The block the decay of an entry of `ExperienceStorage` is counted from, with the
+	/// experience it held then. Only a gain moves it, so the decay does not depend on how often
+	/// it is settled. Entries without one, i.e. created while decay was disabled, start decaying
+	/// from their next update
+	#[pallet::storage]
+	pub type ExperienceDecayedAt<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, ExperienceTypeId),
+		(BlockNumberFor<T>, u128),
+	>;
+
+	/// The last entry of `ExperienceStorage` the `on_idle` decay sweep visited, the next one
+	/// resumes after it even if it has been removed since
+	#[pallet::storage]
+	pub type DecaySweepCursor<T: Config> = StorageValue<_, (T::AccountId, ExperienceTypeId)>;
+
+	/// The blocks in which an approver recently approved the work of a worker, oldest first.
+	/// Only the blocks within `PairWindow` count as repetitions, the pairs without any are
+	/// pruned by the `on_idle` sweep
+	#[pallet::storage]
+	pub type PairInteractions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<BlockNumberFor<T>, T::MaxPairRepetitions>,
+		ValueQuery,
+	>;
+
+	/// The last pair of `PairInteractions` the `on_idle` pruning sweep visited, the next one
+	/// resumes after it even if it has been pruned
+	#[pallet::storage]
+	pub type PairSweepCursor<T: Config> = StorageValue<_, (T::AccountId, T::AccountId)>;
+
+	/// The most recent positive interactions on the work of an account, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn worker_history)]
+	pub type WorkerHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<HistoryEntry<T>, T::MaxPositiveUserInteractions>,
+		ValueQuery,
+	>;
+
+	/// The most recent positive interactions recorded by an account as approver, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn approver_history)]
+	pub type ApproverHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<HistoryEntry<T>, T::MaxPositiveUserInteractions>,
+		ValueQuery,
+	>;
+
+	/// The registry of the experience types (or roles) a user may earn experience in
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn experience_types)]
+	pub type ExperienceTypes<T: Config> =
+		StorageMap<_, Twox64Concat, ExperienceTypeId, ExperienceTypeInfo<T>>;
+
+	/// The id the next registered experience type will get
+	#[pallet::storage]
+	pub type NextExperienceTypeId<T> = StorageValue<_, ExperienceTypeId, ValueQuery>;
+
+	/// The boards a positive interaction may happen on, with their owner and workflow columns
+	///
+	/// TWOX-NOTE: Safe, as increasing integer keys are safe.
+	#[pallet::storage]
+	#[pallet::getter(fn boards)]
+	pub type Boards<T: Config> = StorageMap<_, Twox64Concat, BoardId, Board<T>>;
+
+	/// The id the next created board will get
+	#[pallet::storage]
+	pub type NextBoardId<T> = StorageValue<_, BoardId, ValueQuery>;
+
+	/// The column of the board each task is currently in.
+	/// A task nobody interacted with yet is in the first column of its board.
+	///
+	/// TWOX-NOTE: Safe for the board ids, which are increasing integers. The task ids are chosen
+	/// by the users, so they are hashed.
+	#[pallet::storage]
+	#[pallet::getter(fn task_state)]
+	pub type TaskStates<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BoardId,
+		Blake2_128Concat,
+		TaskId,
+		ColumnIndex,
+		ValueQuery,
+	>;
+
+	/// The number of tasks in each column of a board but the first one, so that `update_board`
+	/// does not drop the columns tasks are still in
+	///
+	/// TWOX-NOTE: Safe, board ids are increasing integers and columns are bounded by `MaxColumns`.
+	#[pallet::storage]
+	pub type ColumnTasks<T> =
+		StorageDoubleMap<_, Twox64Concat, BoardId, Twox64Concat, ColumnIndex, u32, ValueQuery>;
+
+	/// Who is working on a task in its current column, and how far the "ready to be pulled"
+	/// handshake with the next person went
+	///
+	/// TWOX-NOTE: Safe for the board ids, which are increasing integers. The task ids are chosen
+	/// by the users, so they are hashed.
+	#[pallet::storage]
+	#[pallet::getter(fn assignment)]
+	pub type Assignments<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BoardId,
+		Blake2_128Concat,
+		TaskId,
+		Assignment<T::AccountId>,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Names of the experience types to register, they get the ids 0, 1, 2, ... in order
+		pub experience_types: Vec<Vec<u8>>,
+		/// Boards to create, described as in `create_board`: (owner, name, columns)
+		pub boards: Vec<(T::AccountId, Vec<u8>, Vec<(Vec<u8>, ExperienceTypeId, u32)>)>,
+		/// Accounts starting as experts: (who, exp_type, level)
+		pub experts: Vec<(T::AccountId, ExperienceTypeId, u32)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { experience_types: Vec::new(), boards: Vec::new(), experts: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::fund_pallet_accounts();
+			for name in &self.experience_types {
+				Pallet::<T>::do_register_experience_type(name.clone())
+					.expect("genesis experience types must have a valid name");
+			}
+			for (owner, name, columns) in &self.boards {
+				Pallet::<T>::do_create_board(owner.clone(), name.clone(), columns.clone())
+					.expect("genesis boards must be valid");
+			}
+			for (who, exp_type, level) in &self.experts {
+				Pallet::<T>::ensure_active_experience_type(*exp_type)
+					.and_then(|_| Pallet::<T>::do_seed_expert(who.clone(), *exp_type, *level))
+					.expect("genesis experts must refer to a registered experience type");
+			}
+		}
+	}
+
 	//pub type ListOfThings<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored { something: u32, who: T::AccountId },
+		/// `approver` recorded a positive interaction on the work of `worker`, crediting
+		/// `awarded` experience after `repetitions` earlier approvals of the same pair within
+		/// the window. [approver, worker, board_id, task_id, awarded, repetitions]
+		InteractionRecorded {
+			approver: T::AccountId,
+			worker: T::AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			awarded: u128,
+			repetitions: u32,
+		},
+		/// A user started earning experience in a new experience type. [who, exp_type]
+		ExperienceCreated { who: T::AccountId, exp_type: ExperienceTypeId },
+		/// A user earned experience. [who, exp_type, amount]
+		ExperienceGained { who: T::AccountId, exp_type: ExperienceTypeId, amount: u128 },
+		/// A user reached a higher level. [who, exp_type, new_level]
+		LeveledUp { who: T::AccountId, exp_type: ExperienceTypeId, new_level: u32 },
+		/// An inactive user lost experience to decay. [who, exp_type, lost, level]
+		ExperienceDecayed { who: T::AccountId, exp_type: ExperienceTypeId, lost: u128, level: u32 },
+		/// An interaction has been challenged, its jury is to be drawn.
+		/// [dispute_id, challenger, approver, worker, board_id, task_id]
+		InteractionChallenged {
+			dispute_id: DisputeId,
+			challenger: T::AccountId,
+			approver: T::AccountId,
+			worker: T::AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+		},
+		/// The jury of a dispute has been drawn. [dispute_id, jury]
+		JuryDrawn { dispute_id: DisputeId, jury: Vec<T::AccountId> },
+		/// A juror voted on a dispute. [dispute_id, juror, guilty]
+		DisputeVoted { dispute_id: DisputeId, juror: T::AccountId, guilty: bool },
+		/// A dispute has been closed. [dispute_id, verdict]
+		DisputeResolved { dispute_id: DisputeId, verdict: Verdict },
+		/// The experience credited by a revoked interaction has been taken back.
+		/// [who, exp_type, amount, level]
+		ExperienceReverted {
+			who: T::AccountId,
+			exp_type: ExperienceTypeId,
+			amount: u128,
+			level: u32,
+		},
+		/// The bond of an approver found guilty has been slashed. [who, amount]
+		ApproverSlashed { who: T::AccountId, amount: BalanceOf<T> },
+		/// An approver reserved funds to back their approvals. [who, amount]
+		Bonded { who: T::AccountId, amount: BalanceOf<T> },
+		/// An approver started unbonding, the amount is withdrawable from `unlock_at`.
+		/// [who, amount, unlock_at]
+		Unbonding { who: T::AccountId, amount: BalanceOf<T>, unlock_at: BlockNumberFor<T> },
+		/// An approver got back the funds they unbonded. [who, amount]
+		Withdrawn { who: T::AccountId, amount: BalanceOf<T> },
+		/// The owner of a board funded the bounty of a task. [board_id, task_id, funder, amount]
+		BountyFunded {
+			board_id: BoardId,
+			task_id: TaskId,
+			funder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A worker has been paid their share of the bounty of a completed task.
+		/// [board_id, task_id, worker, amount]
+		BountyPaid {
+			board_id: BoardId,
+			task_id: TaskId,
+			worker: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// What was left of a bounty went back to its funder, after cancelling it or failing to
+		/// pay the workers. [board_id, task_id, funder, amount]
+		BountyRefunded {
+			board_id: BoardId,
+			task_id: TaskId,
+			funder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// An epoch is over, what has been earned within it does not change anymore and its
+		/// contributors may claim their share of `rewards`.
+		/// [epoch, experience, contributors, rewards]
+		EpochClosed { epoch: EpochId, experience: u128, contributors: u32, rewards: BalanceOf<T> },
+		/// Funds have been added to the reward pot of the current epoch. [who, amount]
+		RewardsFunded { who: T::AccountId, amount: BalanceOf<T> },
+		/// A contributor has been paid their share of the rewards of an epoch.
+		/// [who, epoch, amount]
+		RewardsClaimed { who: T::AccountId, epoch: EpochId, amount: BalanceOf<T> },
+		/// The rewards of an epoch nobody claimed in time went back to the reward pot.
+		/// [epoch, amount]
+		RewardsExpired { epoch: EpochId, amount: BalanceOf<T> },
+		/// A new board has been created. [board_id, owner]
+		BoardCreated { board_id: BoardId, owner: T::AccountId },
+		/// The name or the columns of a board have been changed. [board_id]
+		BoardUpdated { board_id: BoardId },
+		/// A board has been archived, no more interactions may happen on it. [board_id]
+		BoardArchived { board_id: BoardId },
+		/// A task moved one column forward. [board_id, task_id, src_state, dst_state]
+		TaskMoved {
+			board_id: BoardId,
+			task_id: TaskId,
+			src_state: ColumnIndex,
+			dst_state: ColumnIndex,
+		},
+		/// The worker declared the work in the current column ready to be pulled.
+		/// [board_id, task_id, worker]
+		TaskReady { board_id: BoardId, task_id: TaskId, worker: T::AccountId },
+		/// Someone pulled a task that was ready, and may now approve it. [board_id, task_id, by]
+		TaskPulled { board_id: BoardId, task_id: TaskId, by: T::AccountId },
+		/// A pulled task is ready to be pulled again, `puller` may not approve it anymore.
+		/// [board_id, task_id, puller]
+		TaskReleased { board_id: BoardId, task_id: TaskId, puller: T::AccountId },
+		/// The board owner took a task back from its worker, anyone may work on it again.
+		/// [board_id, task_id, worker]
+		WorkerEvicted { board_id: BoardId, task_id: TaskId, worker: T::AccountId },
+		/// An account has been granted the experience of an expert. [who, exp_type, level]
+		ExpertSeeded { who: T::AccountId, exp_type: ExperienceTypeId, level: u32 },
+		/// A new experience type has been registered. [exp_type, name]
+		ExperienceTypeRegistered { exp_type: ExperienceTypeId, name: Vec<u8> },
+		/// The name or the active flag of an experience type changed. [exp_type, active]
+		ExperienceTypeUpdated { exp_type: ExperienceTypeId, active: bool },
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Error names should be descriptive.
-		NoneValue,
-		/// Errors should have helpful documentation associated with them.
-		StorageOverflow,
 		/// Desired value does not exist in ExdperienceStorage
 		UserExperienceDoesNotExist,
 		/// User already has experience
@@ -122,33 +685,550 @@ pub mod pallet {
 		/// an interaction is identified univoquely by (approver, worker, project_id, task_id,
 		/// src_state, dst_state)
 		InteractionExisting,
+		/// There is no board with the given id
+		BoardNotFound,
+		/// The board has been archived and can not be changed or interacted with anymore
+		BoardArchived,
+		/// Only the owner of the board may perform this operation
+		NotBoardOwner,
+		/// A board or column name is longer than `MaxNameLength`
+		NameTooLong,
+		/// A board has more columns than `MaxColumns`
+		TooManyColumns,
+		/// A board needs at least two columns for a task to move forward
+		NotEnoughColumns,
+		/// Some tasks are still in a column the update drops, or makes the last one
+		ColumnHasTasks,
+		/// No more boards can be created, the board ids are exhausted
+		BoardIdOverflow,
+		/// A task may only move from its current column to the next one
+		InvalidTransition,
+		/// The task already reached the last column of the board
+		TaskCompleted,
+		/// Only the person working on the task in its current column may do this
+		NotAssignee,
+		/// The work on the task has already been declared ready to be pulled
+		TaskAlreadyReady,
+		/// The task has not been declared ready to be pulled by its worker
+		TaskNotReady,
+		/// The worker of a task can not pull it
+		CannotPullOwnTask,
+		/// Nobody pulled the task
+		TaskNotPulled,
+		/// Nobody works on the task in its current column
+		TaskNotAssigned,
+		/// Only the one who pulled the task may give it back
+		NotPuller,
+		/// The approver did not pull the task after its worker declared it ready, so the
+		/// interaction can not be counted
+		HandshakeMissing,
+		/// Nobody may approve their own work
+		SelfInteraction,
+		/// The approver worked on this task in this column, so they can not move it forward
+		ApproverWorkedOnColumn,
+		/// The approver's level in the experience type required by the column is too low
+		ApproverLevelTooLow,
+		/// There is no experience type with the given id in the registry
+		ExperienceTypeNotFound,
+		/// The experience type has been deactivated and can not be used by new columns anymore
+		ExperienceTypeInactive,
+		/// No more experience types can be registered, the ids are exhausted
+		ExperienceTypeIdOverflow,
+		/// More evidence references than `MaxEvidence` were attached
+		TooMuchEvidence,
+		/// There is no recorded interaction with the given identifier
+		InteractionNotFound,
+		/// The interaction was recorded before its metadata existed, what it credited is unknown
+		/// so it can not be challenged. So are the interactions whose experience type is unknown
+		MigratedInteraction,
+		/// The interaction is being disputed or a jury already judged it
+		AlreadyChallenged,
+		/// There are not `JurySize` accounts with `JuryMinLevel` in the experience type, besides
+		/// the parties of the dispute
+		NotEnoughJurors,
+		/// More accounts may be drawn as jurors than the `candidates` given
+		TooManyCandidates,
+		/// The jury of this dispute has already been drawn
+		JuryAlreadyDrawn,
+		/// The randomness was determinable when the interaction was challenged, the jury can
+		/// only be drawn in a later block
+		JuryNotDrawableYet,
+		/// No more disputes can be opened, the dispute ids are exhausted
+		DisputeIdOverflow,
+		/// There is no open dispute with the given id
+		DisputeNotFound,
+		/// Only the jurors drawn for a dispute may vote on it
+		NotAJuror,
+		/// The juror already voted on this dispute
+		AlreadyVoted,
+		/// The voting period of the dispute is over
+		VotingClosed,
+		/// The voting period of the dispute is not over yet
+		VotingStillOpen,
+		/// The bond of the approver does not back the experience their approval grants
+		BondTooLow,
+		/// Can not unbond more than the active bond
+		InsufficientBond,
+		/// The bond is locked by approvals which can still be challenged, see `ChallengePeriod`
+		BondLocked,
+		/// The bond already carries `MaxBondLocks` locks, wait for some to be released
+		TooManyBondLocks,
+		/// The approval is too old to be challenged, see `ChallengePeriod`
+		ChallengePeriodOver,
+		/// There are no unbonded funds to withdraw
+		NothingToWithdraw,
+		/// The unbonded funds are still locked, see `UnbondingPeriod`
+		StillUnbonding,
+		/// There is no bounty for the given task
+		BountyNotFound,
+		/// Only the funder of a bounty may cancel it
+		NotBountyFunder,
+		/// The rewards of an epoch may only be claimed once it closed
+		EpochNotClosed,
+		/// The signer already claimed their share of the rewards of the epoch
+		RewardsAlreadyClaimed,
+		/// The signer has no share of the rewards of the epoch
+		NoRewards,
+		/// The rewards of the epoch can not be claimed anymore, see `RewardClaimEpochs`
+		RewardsExpired,
+	}
+
+	/// Id of a board, assigned incrementally at creation
+	pub type BoardId = u32;
+
+	/// Id of a task inside of a board
+	pub type TaskId = u32;
+
+	/// Position of a column in the workflow of a board, the first column is 0
+	pub type ColumnIndex = u32;
+
+	/// Id of an experience type, assigned incrementally at registration
+	pub type ExperienceTypeId = u32;
+
+	/// A bounded name for boards, columns and experience types
+	pub type BoundedName<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// A step of the board workflow, i.e. TODO, IN PROGRESS, CODE REVIEW
+	pub struct Column<T: Config> {
+		/// Name displayed for this column
+		pub name: BoundedName<T>,
+		/// The role of this step: only an expert of this experience type may pull a task into
+		/// this column, and the work approved by moving a task here earns this experience type
+		pub exp_type: ExperienceTypeId,
+		/// The minimum level in `exp_type` required to pull a task into this column, zero lets
+		/// anyone pull it
+		pub min_level: u32,
+	}
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// A board (or project) where tasks move from the first to the last column through positive
+	/// interactions
+	pub struct Board<T: Config> {
+		/// The account allowed to change or archive the board
+		pub owner: T::AccountId,
+		/// Name of the board
+		pub name: BoundedName<T>,
+		/// The ordered workflow of the board, i.e. NEW, TODO, IN PROGRESS, CODE REVIEW, QA
+		pub columns: BoundedVec<Column<T>, T::MaxColumns>,
+		/// An archived board does not accept interactions anymore
+		pub archived: bool,
+	}
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// A type of experience that a user can have, i.e. Frontend, Backend, Marketing.
+	/// New types are registered by `ExperienceTypeAdminOrigin` without a runtime upgrade
+	pub struct ExperienceTypeInfo<T: Config> {
+		/// Name of the experience type
+		pub name: BoundedName<T>,
+		/// An inactive type keeps the experience earned so far, but new or updated board
+		/// columns can not require it and nobody can be seeded as an expert of it
+		pub active: bool,
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	/// Progress of the handshake between the worker of a column and the next person.
+	/// The board is simplified but it actually has the double of steps:
+	/// TODO | READY FOR IN PROGRESS | IN PROGRESS | READY FOR REVIEW | REVIEW
+	pub enum TaskStatus<AccountId> {
+		/// The worker is still working on the task in this column
+		InProgress,
+		/// The worker said "MY WORK IS READY TO BE PULLED"
+		ReadyToBePulled,
+		/// Someone pulled the task and may record the positive interaction moving it forward
+		Pulled { by: AccountId },
 	}
 
-	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, Copy)]
-	/// This enum represents the different types of experience that a user can have
-	/// Ideally, we want to this to be extensible so that we can add more types of experience
-	pub enum ExperienceType {
-		Frontend,
-		Backend,
-		Marketing,
-		GraphicDesign,
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	/// The person working on a task in its current column
+	pub struct Assignment<AccountId> {
+		/// author of the increment in the current column
+		pub worker: AccountId,
+		/// how far the handshake with the next person went
+		pub status: TaskStatus<AccountId>,
 	}
 
-	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, Copy)]
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
 	#[scale_info(skip_type_params(T))]
 	/// Id that refer univoquely to an interaction between an approver and the owner
 	/// of the increment, corresponding to a specific task of a specific board.
 	/// Increment is referred to the portion of work executed in this task.
 	pub struct InteractionIdentifier<T: Config> {
 		/// a person with the right expertise to validate the increment proposed
-		approver: T::AccountId,
+		pub(crate) approver: T::AccountId,
 		/// author of the increment
-		worker: T::AccountId,
-		/// we need to implement this storage, for now it is an abstract number
-		/// this will be the specific board or project
-		board_id: u32,
+		pub(crate) worker: T::AccountId,
+		/// the specific board or project, see `Boards`
+		pub(crate) board_id: BoardId,
+		/// id that identify the task inside of that board
+		pub(crate) task_id: TaskId,
+		/// the column the task was in before the interaction
+		pub(crate) src_state: ColumnIndex,
+		/// the column the task moved to, always the one following `src_state`
+		pub(crate) dst_state: ColumnIndex,
+	}
+
+	/// Maximum length of an IPFS CID in its binary form
+	pub type MaxCidLength = ConstU32<64>;
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// A reference to the off-chain increment a positive interaction is about
+	pub enum Evidence {
+		/// Hash of a git commit, 20 bytes for SHA-1 repositories and 32 for SHA-256 ones
+		GitCommit(BoundedVec<u8, ConstU32<32>>),
+		/// Hash of the URL of a pull request
+		PullRequest([u8; 32]),
+		/// Binary CID of a document stored on IPFS
+		IpfsCid(BoundedVec<u8, MaxCidLength>),
+	}
+
+	/// The evidence references attached to a single call
+	pub type EvidenceList<T> = BoundedVec<Evidence, <T as Config>::MaxEvidence>;
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// The evidence backing a positive interaction
+	pub struct EvidenceRecord<T: Config> {
+		/// attached by the worker when marking the task ready, i.e. the commit or the PR
+		pub worker: EvidenceList<T>,
+		/// attached by the approver when recording the interaction, i.e. a review
+		pub approver: EvidenceList<T>,
+	}
+
+	/// The moment type of the configured `Time`
+	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// A positive interaction as shown in the history of the worker and of the approver
+	pub struct HistoryEntry<T: Config> {
+		/// The approver in the history of the worker, the worker in the history of the approver
+		pub counterpart: T::AccountId,
+		/// the specific board or project, see `Boards`
+		pub board_id: BoardId,
 		/// id that identify the task inside of that board
-		task_id: u32,
+		pub task_id: TaskId,
+		/// the column the task moved to
+		pub dst_state: ColumnIndex,
+		/// the block the interaction was recorded in
+		pub block: BlockNumberFor<T>,
+		/// the timestamp of that block
+		pub moment: MomentOf<T>,
+	}
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// What is known about a recorded positive interaction, so that it can be audited later.
+	/// The interactions recorded before the metadata existed only know their columns
+	pub struct InteractionMetadata<T: Config> {
+		/// the block the interaction was recorded in, `None` when unknown
+		pub block: Option<BlockNumberFor<T>>,
+		/// the timestamp of that block, `None` when unknown
+		pub moment: Option<MomentOf<T>>,
+		/// the column the task was in before the interaction
+		pub src_state: ColumnIndex,
+		/// the column the task moved to
+		pub dst_state: ColumnIndex,
+		/// the experience type of that column when the interaction was recorded, `None` when
+		/// unknown
+		pub exp_type: Option<ExperienceTypeId>,
+		/// the experience credited to the worker, `None` when unknown
+		pub awarded: Option<u128>,
+		/// the bond of the approver locked by the approval, slashed if the jury finds them
+		/// guilty. Zero for the interactions recorded before bonds were locked
+		pub bond: BalanceOf<T>,
+		/// hash of the evidence backing the approved work, if any
+		pub evidence: Option<T::Hash>,
+	}
+
+	/// Id of a dispute, assigned incrementally when an interaction is challenged
+	pub type DisputeId = u32;
+
+	/// Id of an epoch, starting from 0 and incremented every time an epoch closes
+	pub type EpochId = u32;
+
+	/// The balance type of the configured `Currency`
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The negative imbalance type of the configured `Currency`
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		DefaultNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// The funds an approver reserved to back their approvals
+	pub struct Bond<T: Config> {
+		/// backs the approvals of the account
+		pub active: BalanceOf<T>,
+		/// no longer backs approvals, but can still be slashed until `unlock_at`
+		pub unbonding: BalanceOf<T>,
+		/// the block from which `unbonding` may be withdrawn
+		pub unlock_at: BlockNumberFor<T>,
+		/// parts of `active` backing the approvals which can still be challenged, with the block
+		/// each is released from, in that order. A locked amount can not be unbonded nor back
+		/// other approvals until then
+		pub locks: BoundedVec<(BalanceOf<T>, BlockNumberFor<T>), T::MaxBondLocks>,
+	}
+
+	impl<T: Config> Bond<T> {
+		/// The part of `active` still locked at `now`
+		pub fn locked_at(&self, now: BlockNumberFor<T>) -> BalanceOf<T> {
+			self.locks
+				.iter()
+				.filter(|(_, until)| now < *until)
+				.fold(Zero::zero(), |locked: BalanceOf<T>, (amount, _)| {
+					locked.saturating_add(*amount)
+				})
+		}
+
+		/// Drops the locks released at `now`
+		pub fn release_locks(&mut self, now: BlockNumberFor<T>) {
+			self.locks.retain(|(_, until)| now < *until);
+		}
+
+		/// Locks `amount` until `until`, which is never before the end of the existing locks.
+		/// The approvals of the same block share their lock
+		pub fn lock(&mut self, amount: BalanceOf<T>, until: BlockNumberFor<T>) -> DispatchResult {
+			match self.locks.last_mut() {
+				Some((locked, last)) if *last == until => {
+					*locked = locked.saturating_add(amount);
+					Ok(())
+				},
+				_ => self
+					.locks
+					.try_push((amount, until))
+					.map_err(|_| Error::<T>::TooManyBondLocks.into()),
+			}
+		}
+
+		/// Releases up to `amount` of the lock ending at `until`
+		pub fn unlock(&mut self, amount: BalanceOf<T>, until: BlockNumberFor<T>) {
+			if let Some(index) = self.locks.iter().position(|(_, end)| *end == until) {
+				let locked = &mut self.locks[index].0;
+				*locked = locked.saturating_sub(amount);
+				if locked.is_zero() {
+					self.locks.remove(index);
+				}
+			}
+		}
+	}
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// A payment for the work on a task, held in escrow until the task is completed
+	pub struct Bounty<T: Config> {
+		/// the owner of the board who funded the bounty, refunded if it is cancelled
+		pub funder: T::AccountId,
+		/// the amount held in escrow
+		pub amount: BalanceOf<T>,
+	}
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// The approved work of a worker on a task
+	pub struct Contribution<T: Config> {
+		/// the column the work moved the task to
+		pub dst_state: ColumnIndex,
+		/// the worker whose work was approved
+		pub worker: T::AccountId,
+		/// the experience the approval was worth, before the repetition decay and the epoch cap
+		pub granted: u128,
+		/// the experience the worker was actually credited
+		pub awarded: u128,
+	}
+
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq, Default,
+	)]
+	/// What has been earned through interactions within an epoch
+	pub struct EpochSummary {
+		/// the experience earned by all the accounts
+		pub experience: u128,
+		/// the number of accounts who earned some
+		pub contributors: u32,
+	}
+
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		DefaultNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// The rewards of a closed epoch
+	pub struct EpochReward<T: Config> {
+		/// shared among the contributors of the epoch
+		pub total: BalanceOf<T>,
+		/// what has not been claimed yet, it goes back to the reward pot when the rewards expire
+		pub unclaimed: BalanceOf<T>,
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+	/// How a dispute has been closed
+	pub enum Verdict {
+		/// The majority of the votes found the approver guilty, the interaction is revoked
+		Guilty,
+		/// The approver has not been found guilty, the interaction stands
+		Innocent,
+		/// Nobody voted before the deadline, the interaction may be challenged again
+		NoQuorum,
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+	/// Where a challenged interaction stands
+	pub enum DisputeStatus {
+		/// The jury of this dispute is voting
+		Open(DisputeId),
+		/// The jury reached this verdict, `Guilty` meaning the interaction has been revoked
+		Closed(Verdict),
+	}
+
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, CloneNoBound, PartialEqNoBound, EqNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	/// A challenge of a recorded interaction, judged by a jury of experts
+	pub struct Dispute<T: Config> {
+		/// who challenged the interaction, `deposit` is reserved from them
+		pub challenger: T::AccountId,
+		/// the challenged interaction
+		pub interaction: InteractionIdentifier<T>,
+		/// the experience type the interaction credited, the jurors are experts of it
+		pub exp_type: ExperienceTypeId,
+		/// the deposit reserved from the challenger
+		pub deposit: BalanceOf<T>,
+		/// the block the interaction was challenged in
+		pub challenged_at: BlockNumberFor<T>,
+		/// the jurors drawn among the experts of `exp_type`, empty until drawn
+		pub jury: BoundedVec<T::AccountId, T::JurySize>,
+		/// the votes cast so far, `true` finding the approver guilty
+		pub votes: BoundedVec<(T::AccountId, bool), T::JurySize>,
+		/// the last block the jury may vote in
+		pub deadline: BlockNumberFor<T>,
 	}
 
 	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug)]
@@ -157,7 +1237,8 @@ pub mod pallet {
 	/// Due to the types of experience that a user can have
 	pub struct UserExperience<T: Config> {
 		/// The user's account id
-		/// This allows for querying of user's with specific experience thresholds
+		/// This allows for querying of user's with specific experience thresholds, see
+		/// `AccountsByLevel`
 		pub account_id: T::AccountId,
 		/// The user's experience
 		pub experience: u128,
@@ -169,9 +1250,151 @@ pub mod pallet {
 		pub experience_to_next_level: u128,
 	}
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// The experience of a user in one experience type, as returned by the runtime API
+	pub struct ExperienceInfo {
+		/// The accumulated experience
+		pub experience: u128,
+		/// The level reached with this experience
+		pub level: u32,
+		/// Experience still missing to reach the next level, zero once `MaxLevel` is reached
+		pub experience_to_next_level: u128,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// A positive interaction, as returned by the runtime API
+	pub struct InteractionInfo<AccountId> {
+		/// who approved the increment
+		pub approver: AccountId,
+		/// author of the increment
+		pub worker: AccountId,
+		/// the board the task belongs to
+		pub board_id: BoardId,
+		/// the task inside of that board
+		pub task_id: TaskId,
+		/// the column the task was in before the interaction
+		pub src_state: ColumnIndex,
+		/// the column the task moved to
+		pub dst_state: ColumnIndex,
+	}
+
+	impl<T: Config> From<InteractionIdentifier<T>> for InteractionInfo<T::AccountId> {
+		fn from(upi: InteractionIdentifier<T>) -> Self {
+			InteractionInfo {
+				approver: upi.approver,
+				worker: upi.worker,
+				board_id: upi.board_id,
+				task_id: upi.task_id,
+				src_state: upi.src_state,
+				dst_state: upi.dst_state,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// A page of accounts with their level in an experience type, by ascending level. Pass
+	/// `next_cursor` back to get the following page, there are no more accounts when it is `None`
+	pub struct LevelPage<AccountId> {
+		/// the accounts of this page with their level
+		pub accounts: Vec<(AccountId, u32)>,
+		/// opaque token pointing after the last account of this page
+		pub next_cursor: Option<Vec<u8>>,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// A page of interactions. Pass `next_cursor` back to get the following page, there are no
+	/// more interactions when it is `None`
+	pub struct InteractionPage<AccountId> {
+		/// the interactions of this page
+		pub interactions: Vec<InteractionInfo<AccountId>>,
+		/// opaque token pointing after the last interaction of this page
+		pub next_cursor: Option<Vec<u8>>,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// The evidence backing a positive interaction, as returned by the runtime API
+	pub struct EvidenceInfo {
+		/// attached by the worker when marking the task ready
+		pub worker: Vec<Evidence>,
+		/// attached by the approver when recording the interaction
+		pub approver: Vec<Evidence>,
+	}
+
+	impl<T: Config> From<EvidenceRecord<T>> for EvidenceInfo {
+		fn from(record: EvidenceRecord<T>) -> Self {
+			EvidenceInfo {
+				worker: record.worker.into_inner(),
+				approver: record.approver.into_inner(),
+			}
+		}
+	}
+
+	impl<T: Config> From<UserExperience<T>> for ExperienceInfo {
+		fn from(experience: UserExperience<T>) -> Self {
+			ExperienceInfo {
+				experience: experience.experience,
+				level: experience.level,
+				experience_to_next_level: experience.experience_to_next_level,
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Closes the current epoch once it lasted `EpochLength` blocks
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let length = T::EpochLength::get();
+			if length.is_zero() {
+				return Weight::zero()
+			}
+			if n.saturating_sub(EpochStart::<T>::get()) < length {
+				return T::DbWeight::get().reads(1)
+			}
+			Self::close_epoch(n);
+			T::WeightInfo::close_epoch()
+		}
+
+		/// Spends the leftover weight of the block applying the pending decay to user
+		/// experiences, then pruning the pairs of `PairInteractions` outside of `PairWindow`,
+		/// then the entries of the epochs whose rewards expired. The sweeps resume where the
+		/// previous block stopped
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut used = Weight::zero();
+			if T::ExperienceHalfLife::get().is_some() {
+				used = Self::idle_sweep(
+					remaining_weight,
+					T::WeightInfo::sweep_decay,
+					Self::sweep_decay,
+				);
+			}
+			used = used.saturating_add(Self::idle_sweep(
+				remaining_weight.saturating_sub(used),
+				T::WeightInfo::sweep_pairs,
+				Self::sweep_pairs,
+			));
+			used.saturating_add(Self::idle_sweep(
+				remaining_weight.saturating_sub(used),
+				T::WeightInfo::sweep_epochs,
+				Self::sweep_epochs,
+			))
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::RewardClaimEpochs::get() >= 1,
+				"`RewardClaimEpochs` must be at least 1, the rewards could never be claimed"
+			);
+		}
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// An example dispatchable that takes a singles value as a parameter, writes the value to
@@ -182,57 +1405,844 @@ pub mod pallet {
 		// 	let _now = <timestamp::Pallet<T>>::get();
 		// 	Ok(())
 		// }
+		/// Records a positive interaction of the signer (approver) on the work done by `worker`,
+		/// moving the task from its current column to `dst_state`, and credits the worker with
+		/// experience of the type the destination column requires: `ExperiencePerInteraction`
+		/// weighted by the level of the approver in that type, then reduced if the approver
+		/// already approved the worker within `PairWindow`, and capped by what the worker may
+		/// still earn within the epoch, see `MaxExperiencePerEpoch`.
+		///
+		/// `dst_state` must be the column right after the one the task is in, and the
+		/// interaction only counts if `worker` marked the task ready and the approver pulled it.
+		/// The approver must have the level the destination column requires and a bond backing
+		/// the weighted experience, see `BondPerExperience`, which the approval locks until it
+		/// can no longer be challenged. They become the worker of the task
+		/// in its new column. Once the task reaches the last column its bounty, if any, is paid.
+		///
+		/// The progress can never be done by the same person who worked on this specific task
+		/// in this specific column.
 		#[pallet::call_index(2)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(T::WeightInfo::interact(evidence.len() as u32))]
 		pub fn interact(
 			origin: OriginFor<T>,
 			worker: T::AccountId,
-			board_id: u32,
-			task_id: u32,
+			board_id: BoardId,
+			task_id: TaskId,
+			dst_state: ColumnIndex,
+			evidence: Vec<Evidence>,
 		) -> DispatchResult {
 			let approver = ensure_signed(origin)?;
-			let upi = InteractionIdentifier::<T> { approver, worker, board_id, task_id };
-			return Self::store_interaction(upi)
-		}
-
-		/// An example dispatchable that may throw a custom error.
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::cause_error())]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-
-			// Read a value from storage.
-			match <Something<T>>::get() {
-				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
-				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
-				},
+			let evidence = Self::bounded_evidence(evidence)?;
+			ensure!(approver != worker, Error::<T>::SelfInteraction);
+			let board = Self::boards(board_id).ok_or(Error::<T>::BoardNotFound)?;
+			ensure!(!board.archived, Error::<T>::BoardArchived);
+
+			let src_state = Self::task_state(board_id, task_id);
+			let next_state = src_state.saturating_add(1);
+			ensure!((next_state as usize) < board.columns.len(), Error::<T>::TaskCompleted);
+			ensure!(dst_state == next_state, Error::<T>::InvalidTransition);
+			let dst_column = &board.columns[dst_state as usize];
+			let approver_level = Self::ensure_qualified(&approver, dst_column)?;
+			let exp_type = dst_column.exp_type;
+			let granted =
+				T::AwardWeighting::weigh(T::ExperiencePerInteraction::get(), approver_level);
+			// Every approval locks its own bond, the ones still locked do not back it
+			let now = frame_system::Pallet::<T>::block_number();
+			let required_bond = Self::required_bond(granted);
+			let mut bond = Bonds::<T>::get(&approver);
+			bond.release_locks(now);
+			let locked = bond.locked_at(now);
+			ensure!(bond.active.saturating_sub(locked) >= required_bond, Error::<T>::BondTooLow);
+			if !required_bond.is_zero() {
+				bond.lock(required_bond, Self::lock_end(now))?;
+			}
+
+			let assignment =
+				Self::assignment(board_id, task_id).ok_or(Error::<T>::HandshakeMissing)?;
+			ensure!(assignment.worker != approver, Error::<T>::ApproverWorkedOnColumn);
+			ensure!(assignment.worker == worker, Error::<T>::NotAssignee);
+			ensure!(
+				assignment.status == TaskStatus::Pulled { by: approver.clone() },
+				Error::<T>::HandshakeMissing
+			);
+
+			let upi = InteractionIdentifier::<T> {
+				approver: approver.clone(),
+				worker: worker.clone(),
+				board_id,
+				task_id,
+				src_state,
+				dst_state,
+			};
+			let record = EvidenceRecord::<T> {
+				worker: PendingEvidence::<T>::take(board_id, task_id).unwrap_or_default(),
+				approver: evidence,
+			};
+			let evidence_hash = (!record.worker.is_empty() || !record.approver.is_empty())
+				.then(|| T::Hashing::hash_of(&record));
+			let repetitions = Self::record_pair_interaction(&approver, &worker);
+			let awarded = T::RepetitionDecay::decay(granted, repetitions);
+			let awarded = Self::record_epoch_experience(&worker, awarded);
+			let metadata = InteractionMetadata::<T> {
+				block: Some(now),
+				moment: Some(T::Time::now()),
+				src_state,
+				dst_state,
+				exp_type: Some(exp_type),
+				awarded: Some(awarded),
+				bond: required_bond,
+				evidence: evidence_hash,
+			};
+			if evidence_hash.is_some() {
+				InteractionEvidence::<T>::insert(&upi, record);
+			}
+			Self::store_interaction(upi, metadata)?;
+			if !required_bond.is_zero() {
+				Bonds::<T>::insert(&approver, bond);
+			}
+			TaskStates::<T>::insert(board_id, task_id, dst_state);
+			if src_state > 0 {
+				ColumnTasks::<T>::mutate(board_id, src_state, |tasks| {
+					*tasks = tasks.saturating_sub(1)
+				});
+			}
+			ColumnTasks::<T>::mutate(board_id, dst_state, |tasks| *tasks = tasks.saturating_add(1));
+			TaskContributions::<T>::mutate(board_id, task_id, |contributions| {
+				// Can not fail, a task moves through every column at most once
+				let _ = contributions.try_push(Contribution {
+					dst_state,
+					worker: worker.clone(),
+					granted,
+					awarded,
+				});
+			});
+			let completed = (dst_state as usize) + 1 >= board.columns.len();
+			if !completed {
+				let assignment =
+					Assignment { worker: approver.clone(), status: TaskStatus::InProgress };
+				Assignments::<T>::insert(board_id, task_id, assignment);
+			} else {
+				// Nobody works on a task that reached the last column
+				Assignments::<T>::remove(board_id, task_id);
+			}
+			Self::add_user_experience(worker.clone(), exp_type, awarded)?;
+			Self::record_history(&approver, &worker, board_id, task_id, dst_state);
+
+			Self::deposit_event(Event::InteractionRecorded {
+				approver,
+				worker,
+				board_id,
+				task_id,
+				awarded,
+				repetitions,
+			});
+			Self::deposit_event(Event::TaskMoved { board_id, task_id, src_state, dst_state });
+			if completed {
+				Self::release_bounty(board_id, task_id);
+			}
+			Ok(())
+		}
+
+		/// Creates a new board owned by the signer, with the given name and ordered columns.
+		/// Every column is described by its name, the experience type of its role and the
+		/// minimum level of that experience type required to pull a task into it.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::create_board(columns.len() as u32))]
+		pub fn create_board(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			columns: Vec<(Vec<u8>, ExperienceTypeId, u32)>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let board_id = Self::do_create_board(owner.clone(), name, columns)?;
+
+			Self::deposit_event(Event::BoardCreated { board_id, owner });
+			Ok(())
+		}
+
+		/// Renames a board and replaces its columns. Only the owner may update a board.
+		/// The columns tasks are in may not be dropped, nor become the last one: their tasks would
+		/// be stranded, or completed without their bounty being paid. The tasks of the last column
+		/// are completed and do not hold it
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::update_board(columns.len() as u32))]
+		pub fn update_board(
+			origin: OriginFor<T>,
+			board_id: BoardId,
+			name: Vec<u8>,
+			columns: Vec<(Vec<u8>, ExperienceTypeId, u32)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut board = Self::owned_board(&who, board_id)?;
+			let columns = Self::bounded_columns(columns)?;
+			let last = columns.len() as ColumnIndex - 1;
+			for index in last..board.columns.len() as ColumnIndex - 1 {
+				ensure!(ColumnTasks::<T>::get(board_id, index) == 0, Error::<T>::ColumnHasTasks);
+			}
+			board.name = Self::bounded_name(name)?;
+			board.columns = columns;
+			Boards::<T>::insert(board_id, board);
+
+			Self::deposit_event(Event::BoardUpdated { board_id });
+			Ok(())
+		}
+
+		/// Archives a board, interactions on it are rejected from now on. Only the owner may
+		/// archive a board
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::archive_board())]
+		pub fn archive_board(origin: OriginFor<T>, board_id: BoardId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut board = Self::owned_board(&who, board_id)?;
+			board.archived = true;
+			Boards::<T>::insert(board_id, board);
+
+			Self::deposit_event(Event::BoardArchived { board_id });
+			Ok(())
+		}
+
+		/// Declares the work on a task in its current column as ready to be pulled by the next
+		/// person. Only the worker of the column may do it; the first one marking a task nobody
+		/// works on becomes its worker, until the board owner takes it back through `release_task`.
+		/// The `evidence` of the work is kept with the interaction approving it.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::mark_ready(evidence.len() as u32))]
+		pub fn mark_ready(
+			origin: OriginFor<T>,
+			board_id: BoardId,
+			task_id: TaskId,
+			evidence: Vec<Evidence>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let evidence = Self::bounded_evidence(evidence)?;
+			let board = Self::boards(board_id).ok_or(Error::<T>::BoardNotFound)?;
+			ensure!(!board.archived, Error::<T>::BoardArchived);
+			let next_state = Self::task_state(board_id, task_id).saturating_add(1);
+			ensure!((next_state as usize) < board.columns.len(), Error::<T>::TaskCompleted);
+
+			if let Some(assignment) = Self::assignment(board_id, task_id) {
+				ensure!(assignment.worker == who, Error::<T>::NotAssignee);
+				ensure!(assignment.status == TaskStatus::InProgress, Error::<T>::TaskAlreadyReady);
+			}
+			let assignment =
+				Assignment { worker: who.clone(), status: TaskStatus::ReadyToBePulled };
+			Assignments::<T>::insert(board_id, task_id, assignment);
+			if evidence.is_empty() {
+				PendingEvidence::<T>::remove(board_id, task_id);
+			} else {
+				PendingEvidence::<T>::insert(board_id, task_id, evidence);
+			}
+
+			Self::deposit_event(Event::TaskReady { board_id, task_id, worker: who });
+			Ok(())
+		}
+
+		/// Pulls a task that its worker declared ready, so that the signer may approve it through
+		/// `interact`. The signer may give it back through `unpull`, and the board owner take it
+		/// back through `release_task`
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::pull())]
+		pub fn pull(origin: OriginFor<T>, board_id: BoardId, task_id: TaskId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let board = Self::boards(board_id).ok_or(Error::<T>::BoardNotFound)?;
+			ensure!(!board.archived, Error::<T>::BoardArchived);
+
+			let mut assignment =
+				Self::assignment(board_id, task_id).ok_or(Error::<T>::TaskNotReady)?;
+			ensure!(assignment.status == TaskStatus::ReadyToBePulled, Error::<T>::TaskNotReady);
+			ensure!(assignment.worker != who, Error::<T>::CannotPullOwnTask);
+			let next_state = Self::task_state(board_id, task_id).saturating_add(1);
+			let column = board.columns.get(next_state as usize).ok_or(Error::<T>::TaskCompleted)?;
+			Self::ensure_qualified(&who, column)?;
+			assignment.status = TaskStatus::Pulled { by: who.clone() };
+			Assignments::<T>::insert(board_id, task_id, assignment);
+
+			Self::deposit_event(Event::TaskPulled { board_id, task_id, by: who });
+			Ok(())
+		}
+
+		/// Grants `who` the experience needed to reach `level` in `exp_type`, so that new boards
+		/// have experts able to pull their tasks. Experience counts on every board, so only
+		/// `ExperienceTypeAdminOrigin` may seed experts.
+		/// Nobody loses experience by being seeded.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::seed_expert((*level).min(T::MaxLevel::get())))]
+		pub fn seed_expert(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			exp_type: ExperienceTypeId,
+			level: u32,
+		) -> DispatchResult {
+			T::ExperienceTypeAdminOrigin::ensure_origin(origin)?;
+			Self::ensure_active_experience_type(exp_type)?;
+
+			Self::do_seed_expert(who.clone(), exp_type, level)?;
+
+			Self::deposit_event(Event::ExpertSeeded { who, exp_type, level });
+			Ok(())
+		}
+
+		/// Adds a new active experience type to the registry, so that boards can require it
+		/// and users can earn experience in it
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::register_experience_type())]
+		pub fn register_experience_type(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			T::ExperienceTypeAdminOrigin::ensure_origin(origin)?;
+			let exp_type = Self::do_register_experience_type(name.clone())?;
+
+			Self::deposit_event(Event::ExperienceTypeRegistered { exp_type, name });
+			Ok(())
+		}
+
+		/// Renames an experience type and (de)activates it. The experience already earned in an
+		/// inactive type is kept, and columns already requiring it keep working.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::update_experience_type())]
+		pub fn update_experience_type(
+			origin: OriginFor<T>,
+			exp_type: ExperienceTypeId,
+			name: Vec<u8>,
+			active: bool,
+		) -> DispatchResult {
+			T::ExperienceTypeAdminOrigin::ensure_origin(origin)?;
+			let mut info =
+				Self::experience_types(exp_type).ok_or(Error::<T>::ExperienceTypeNotFound)?;
+			info.name = Self::bounded_name(name)?;
+			info.active = active;
+			ExperienceTypes::<T>::insert(exp_type, info);
+
+			Self::deposit_event(Event::ExperienceTypeUpdated { exp_type, active });
+			Ok(())
+		}
+
+		/// Challenges a recorded interaction the signer believes approved fraudulent work,
+		/// reserving `ChallengeDeposit` from them. A jury of `JurySize` experts of the
+		/// experience type the interaction credited is then drawn with `draw_jury`, and may
+		/// vote until `VotingPeriod` blocks after the challenge. Interactions can only be
+		/// challenged during the `ChallengePeriod` blocks following their approval.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::challenge_interaction())]
+		pub fn challenge_interaction(
+			origin: OriginFor<T>,
+			approver: T::AccountId,
+			worker: T::AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			src_state: ColumnIndex,
+			dst_state: ColumnIndex,
+		) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+			let upi = InteractionIdentifier::<T> {
+				approver: approver.clone(),
+				worker: worker.clone(),
+				board_id,
+				task_id,
+				src_state,
+				dst_state,
+			};
+			let metadata = Interaction::<T>::get(&upi).ok_or(Error::<T>::InteractionNotFound)?;
+			ensure!(metadata.awarded.is_some(), Error::<T>::MigratedInteraction);
+			// The board may have changed since, the interaction keeps what it credited
+			let exp_type = metadata.exp_type.ok_or(Error::<T>::MigratedInteraction)?;
+			let approved_at = metadata.block.ok_or(Error::<T>::MigratedInteraction)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now <= approved_at.saturating_add(T::ChallengePeriod::get()),
+				Error::<T>::ChallengePeriodOver
+			);
+			ensure!(!DisputedInteractions::<T>::contains_key(&upi), Error::<T>::AlreadyChallenged);
+
+			let dispute_id = NextDisputeId::<T>::get();
+			let next_dispute_id = dispute_id.checked_add(1).ok_or(Error::<T>::DisputeIdOverflow)?;
+			let deposit = T::ChallengeDeposit::get();
+			T::Currency::reserve(&challenger, deposit)?;
+			let dispute = Dispute::<T> {
+				challenger: challenger.clone(),
+				interaction: upi.clone(),
+				exp_type,
+				deposit,
+				challenged_at: now,
+				jury: BoundedVec::default(),
+				votes: BoundedVec::default(),
+				deadline: now.saturating_add(T::VotingPeriod::get()),
+			};
+			Disputes::<T>::insert(dispute_id, dispute);
+			DisputedInteractions::<T>::insert(&upi, DisputeStatus::Open(dispute_id));
+			NextDisputeId::<T>::put(next_dispute_id);
+
+			Self::deposit_event(Event::InteractionChallenged {
+				dispute_id,
+				challenger,
+				approver,
+				worker,
+				board_id,
+				task_id,
+			});
+			Ok(())
+		}
+
+		/// Draws the jury of a dispute, leaving out its parties. The randomness it is drawn with
+		/// must not have been determinable when the interaction was challenged, so depending on
+		/// `Randomness` it can only be drawn some blocks after the challenge. `candidates` is at
+		/// least the number of accounts indexed with `JuryMinLevel` or more in the experience
+		/// type of the dispute, which are all visited. Anyone may do it
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::draw_jury(*candidates))]
+		pub fn draw_jury(
+			origin: OriginFor<T>,
+			dispute_id: DisputeId,
+			candidates: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.jury.is_empty(), Error::<T>::JuryAlreadyDrawn);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= dispute.deadline, Error::<T>::VotingClosed);
+			let (seed, known_since) = T::Randomness::random(&(b"popi/jury", dispute_id).encode());
+			ensure!(known_since > dispute.challenged_at, Error::<T>::JuryNotDrawableYet);
+
+			let upi = &dispute.interaction;
+			let parties = [&dispute.challenger, &upi.approver, &upi.worker];
+			let (jury, visited) = Self::select_jury(seed, dispute.exp_type, &parties, candidates)?;
+			dispute.jury = jury.clone();
+			Disputes::<T>::insert(dispute_id, dispute);
+
+			Self::deposit_event(Event::JuryDrawn { dispute_id, jury: jury.into_inner() });
+			Ok(Some(T::WeightInfo::draw_jury(visited)).into())
+		}
+
+		/// Votes on a dispute the signer is a juror of, `guilty` if the approver approved work
+		/// they should not have. The dispute is resolved as soon as the whole jury voted
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::vote())]
+		pub fn vote(origin: OriginFor<T>, dispute_id: DisputeId, guilty: bool) -> DispatchResult {
+			let juror = ensure_signed(origin)?;
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= dispute.deadline, Error::<T>::VotingClosed);
+			ensure!(dispute.jury.contains(&juror), Error::<T>::NotAJuror);
+			ensure!(
+				!dispute.votes.iter().any(|(voter, _)| *voter == juror),
+				Error::<T>::AlreadyVoted
+			);
+			// Can not fail, every juror votes at most once
+			dispute
+				.votes
+				.try_push((juror.clone(), guilty))
+				.map_err(|_| Error::<T>::AlreadyVoted)?;
+
+			Self::deposit_event(Event::DisputeVoted { dispute_id, juror, guilty });
+			if dispute.votes.len() == dispute.jury.len() {
+				Self::resolve(dispute_id, dispute)
+			} else {
+				Disputes::<T>::insert(dispute_id, dispute);
+				Ok(())
 			}
 		}
+
+		/// Closes a dispute once its voting period is over with the votes cast so far. Anyone
+		/// may do it
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(origin: OriginFor<T>, dispute_id: DisputeId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > dispute.deadline, Error::<T>::VotingStillOpen);
+			Self::resolve(dispute_id, dispute)
+		}
+
+		/// Reserves `amount` more of the signer's funds to back their approvals
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::bond())]
+		pub fn bond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::reserve(&who, amount)?;
+			let mut bond = Bonds::<T>::get(&who);
+			bond.active = bond.active.saturating_add(amount);
+			Self::store_bond(&who, bond);
+
+			Self::deposit_event(Event::Bonded { who, amount });
+			Ok(())
+		}
+
+		/// Stops `amount` of the signer's bond from backing their approvals. It stays reserved,
+		/// and slashable, for `UnbondingPeriod` blocks; unbonding more restarts the period.
+		/// The bond locked by approvals which can still be challenged can not be unbonded
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::unbond())]
+		pub fn unbond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut bond = Bonds::<T>::get(&who);
+			ensure!(bond.active >= amount, Error::<T>::InsufficientBond);
+			let now = frame_system::Pallet::<T>::block_number();
+			bond.release_locks(now);
+			ensure!(
+				bond.active.saturating_sub(bond.locked_at(now)) >= amount,
+				Error::<T>::BondLocked
+			);
+			let unlock_at = now.saturating_add(T::UnbondingPeriod::get());
+			bond.active -= amount;
+			bond.unbonding = bond.unbonding.saturating_add(amount);
+			bond.unlock_at = unlock_at;
+			Self::store_bond(&who, bond);
+
+			Self::deposit_event(Event::Unbonding { who, amount, unlock_at });
+			Ok(())
+		}
+
+		/// Gives the signer back the funds they unbonded, once `UnbondingPeriod` is over
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut bond = Bonds::<T>::get(&who);
+			ensure!(!bond.unbonding.is_zero(), Error::<T>::NothingToWithdraw);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= bond.unlock_at, Error::<T>::StillUnbonding);
+			let amount = sp_std::mem::take(&mut bond.unbonding);
+			T::Currency::unreserve(&who, amount);
+			Self::store_bond(&who, bond);
+
+			Self::deposit_event(Event::Withdrawn { who, amount });
+			Ok(())
+		}
+
+		/// Moves `amount` of the signer's funds into escrow as the bounty of a task of a board
+		/// they own. When the task reaches the last column, the bounty is split among the workers
+		/// whose work moved it there, in proportion to what their approved work was worth.
+		/// Funding a task again adds to its bounty
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::fund_bounty())]
+		pub fn fund_bounty(
+			origin: OriginFor<T>,
+			board_id: BoardId,
+			task_id: TaskId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let board = Self::owned_board(&who, board_id)?;
+			let next_state = Self::task_state(board_id, task_id).saturating_add(1);
+			ensure!((next_state as usize) < board.columns.len(), Error::<T>::TaskCompleted);
+			let mut bounty = Bounties::<T>::get(board_id, task_id)
+				.unwrap_or(Bounty { funder: who.clone(), amount: Zero::zero() });
+			ensure!(bounty.funder == who, Error::<T>::NotBountyFunder);
+			T::Currency::transfer(&who, &Self::bounty_account(), amount, KeepAlive)?;
+			bounty.amount = bounty.amount.saturating_add(amount);
+			Bounties::<T>::insert(board_id, task_id, bounty);
+
+			Self::deposit_event(Event::BountyFunded { board_id, task_id, funder: who, amount });
+			Ok(())
+		}
+
+		/// Cancels the bounty of a task that has not been completed, refunding its funder.
+		/// Only the funder may cancel it
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::cancel_bounty())]
+		pub fn cancel_bounty(
+			origin: OriginFor<T>,
+			board_id: BoardId,
+			task_id: TaskId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bounty = Bounties::<T>::get(board_id, task_id).ok_or(Error::<T>::BountyNotFound)?;
+			ensure!(bounty.funder == who, Error::<T>::NotBountyFunder);
+			T::Currency::transfer(&Self::bounty_account(), &who, bounty.amount, KeepAlive)?;
+			Bounties::<T>::remove(board_id, task_id);
+
+			Self::deposit_event(Event::BountyRefunded {
+				board_id,
+				task_id,
+				funder: who,
+				amount: bounty.amount,
+			});
+			Ok(())
+		}
+
+		/// Moves `amount` of the signer's funds, e.g. a treasury account, into the reward pot
+		/// shared among the contributors of the current epoch when it closes
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::fund_rewards())]
+		pub fn fund_rewards(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::transfer(&who, &Self::reward_account(), amount, KeepAlive)?;
+			RewardPot::<T>::mutate(|pot| *pot = pot.saturating_add(amount));
+
+			Self::deposit_event(Event::RewardsFunded { who, amount });
+			Ok(())
+		}
+
+		/// Pays the signer their share of the rewards of a closed epoch, in proportion to the
+		/// experience they earned through interactions within it. Rewards are only paid when
+		/// claimed, so that closing an epoch does not depend on the number of contributors. They
+		/// may be claimed until `RewardClaimEpochs` more epochs closed
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(origin: OriginFor<T>, epoch: EpochId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let current = CurrentEpoch::<T>::get();
+			ensure!(epoch < current, Error::<T>::EpochNotClosed);
+			ensure!(Self::rewards_claimable(epoch, current), Error::<T>::RewardsExpired);
+			ensure!(
+				!RewardsClaimed::<T>::contains_key(epoch, &who),
+				Error::<T>::RewardsAlreadyClaimed
+			);
+			let earned = EpochExperience::<T>::get(epoch, &who);
+			let total = EpochTotals::<T>::get(epoch).experience;
+			ensure!(earned > 0 && total > 0, Error::<T>::NoRewards);
+			let mut rewards = EpochRewards::<T>::get(epoch);
+			let amount = Perbill::from_rational(earned, total).mul_floor(rewards.total);
+			ensure!(!amount.is_zero(), Error::<T>::NoRewards);
+			T::Currency::transfer(&Self::reward_account(), &who, amount, KeepAlive)?;
+			rewards.unclaimed = rewards.unclaimed.saturating_sub(amount);
+			EpochRewards::<T>::insert(epoch, rewards);
+			RewardsClaimed::<T>::insert(epoch, &who, ());
+
+			Self::deposit_event(Event::RewardsClaimed { who, epoch, amount });
+			Ok(())
+		}
+
+		/// Gives back a task the signer pulled but will not approve, so that someone else may
+		/// pull it
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::unpull())]
+		pub fn unpull(origin: OriginFor<T>, board_id: BoardId, task_id: TaskId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (assignment, puller) = Self::pulled_task(board_id, task_id)?;
+			ensure!(puller == who, Error::<T>::NotPuller);
+			Self::release_pulled_task(board_id, task_id, assignment, puller);
+			Ok(())
+		}
+
+		/// Takes a pulled task back from whoever pulled it, so that someone else may pull it.
+		/// A task nobody pulled is taken back from its worker instead, together with the evidence
+		/// they gave, so that anyone may mark it ready again.
+		/// Only the owner of the board may do it, i.e. when the puller or the worker is gone or
+		/// squats the task
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::release_task())]
+		pub fn release_task(
+			origin: OriginFor<T>,
+			board_id: BoardId,
+			task_id: TaskId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::owned_board(&who, board_id)?;
+			let assignment =
+				Self::assignment(board_id, task_id).ok_or(Error::<T>::TaskNotAssigned)?;
+			if let TaskStatus::Pulled { by } = assignment.status.clone() {
+				Self::release_pulled_task(board_id, task_id, assignment, by);
+				return Ok(())
+			}
+			Assignments::<T>::remove(board_id, task_id);
+			PendingEvidence::<T>::remove(board_id, task_id);
+
+			Self::deposit_event(Event::WorkerEvicted {
+				board_id,
+				task_id,
+				worker: assignment.worker,
+			});
+			Ok(())
+		}
 	}
 
 	/// The following impl and functions should not be accessible by the user
 	/// For any function that needs to be accessible by the user, use the above implementation
 	/// (under #[pallet::call] attribute)
 	impl<T: Config> Pallet<T> {
-		pub fn store_interaction(upi: InteractionIdentifier<T>) -> DispatchResult {
+		/// Returns the board if `who` owns it and it has not been archived yet
+		fn owned_board(who: &T::AccountId, board_id: BoardId) -> Result<Board<T>, DispatchError> {
+			let board = Self::boards(board_id).ok_or(Error::<T>::BoardNotFound)?;
+			ensure!(board.owner == *who, Error::<T>::NotBoardOwner);
+			ensure!(!board.archived, Error::<T>::BoardArchived);
+			Ok(board)
+		}
+
+		/// The assignment of a pulled task together with who pulled it
+		fn pulled_task(
+			board_id: BoardId,
+			task_id: TaskId,
+		) -> Result<(Assignment<T::AccountId>, T::AccountId), DispatchError> {
+			let assignment =
+				Self::assignment(board_id, task_id).ok_or(Error::<T>::TaskNotPulled)?;
+			match assignment.status.clone() {
+				TaskStatus::Pulled { by } => Ok((assignment, by)),
+				_ => Err(Error::<T>::TaskNotPulled.into()),
+			}
+		}
+
+		/// Makes a pulled task ready to be pulled again
+		fn release_pulled_task(
+			board_id: BoardId,
+			task_id: TaskId,
+			mut assignment: Assignment<T::AccountId>,
+			puller: T::AccountId,
+		) {
+			assignment.status = TaskStatus::ReadyToBePulled;
+			Assignments::<T>::insert(board_id, task_id, assignment);
+			Self::deposit_event(Event::TaskReleased { board_id, task_id, puller });
+		}
+
+		/// Stores a new board and returns its id
+		fn do_create_board(
+			owner: T::AccountId,
+			name: Vec<u8>,
+			columns: Vec<(Vec<u8>, ExperienceTypeId, u32)>,
+		) -> Result<BoardId, DispatchError> {
+			let board = Board::<T> {
+				owner,
+				name: Self::bounded_name(name)?,
+				columns: Self::bounded_columns(columns)?,
+				archived: false,
+			};
+
+			let board_id = NextBoardId::<T>::get();
+			let next_board_id = board_id.checked_add(1).ok_or(Error::<T>::BoardIdOverflow)?;
+			Boards::<T>::insert(board_id, board);
+			NextBoardId::<T>::put(next_board_id);
+			Ok(board_id)
+		}
+
+		/// Adds an active experience type to the registry and returns its id
+		fn do_register_experience_type(name: Vec<u8>) -> Result<ExperienceTypeId, DispatchError> {
+			let info = ExperienceTypeInfo::<T> { name: Self::bounded_name(name)?, active: true };
+
+			let exp_type = NextExperienceTypeId::<T>::get();
+			let next_exp_type =
+				exp_type.checked_add(1).ok_or(Error::<T>::ExperienceTypeIdOverflow)?;
+			ExperienceTypes::<T>::insert(exp_type, info);
+			NextExperienceTypeId::<T>::put(next_exp_type);
+			Ok(exp_type)
+		}
+
+		/// Raises the experience of `who` in `exp_type` to what `level` requires
+		fn do_seed_expert(
+			who: T::AccountId,
+			exp_type: ExperienceTypeId,
+			level: u32,
+		) -> DispatchResult {
+			let threshold = experience_for_level::<T::LevelCurve>(level, T::MaxLevel::get());
+			let current = Self::current_experience(&who, exp_type)
+				.map(|experience| experience.experience)
+				.unwrap_or_default();
+			Self::add_user_experience(who, exp_type, threshold.saturating_sub(current))
+		}
+
+		fn bounded_evidence(evidence: Vec<Evidence>) -> Result<EvidenceList<T>, DispatchError> {
+			evidence.try_into().map_err(|_| Error::<T>::TooMuchEvidence.into())
+		}
+
+		fn bounded_name(name: Vec<u8>) -> Result<BoundedName<T>, DispatchError> {
+			name.try_into().map_err(|_| Error::<T>::NameTooLong.into())
+		}
+
+		fn bounded_columns(
+			columns: Vec<(Vec<u8>, ExperienceTypeId, u32)>,
+		) -> Result<BoundedVec<Column<T>, T::MaxColumns>, DispatchError> {
+			ensure!(columns.len() >= 2, Error::<T>::NotEnoughColumns);
+			let columns = columns
+				.into_iter()
+				.map(|(name, exp_type, min_level)| {
+					Self::ensure_active_experience_type(exp_type)?;
+					Ok(Column::<T> { name: Self::bounded_name(name)?, exp_type, min_level })
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			columns.try_into().map_err(|_| Error::<T>::TooManyColumns.into())
+		}
+
+		/// New columns and experts may only refer to registered, active experience types
+		fn ensure_active_experience_type(exp_type: ExperienceTypeId) -> DispatchResult {
+			let info =
+				Self::experience_types(exp_type).ok_or(Error::<T>::ExperienceTypeNotFound)?;
+			ensure!(info.active, Error::<T>::ExperienceTypeInactive);
+			Ok(())
+		}
+
+		/// Only an expert of the column role may pull a task into it, returns the level of `who`
+		/// in that role
+		fn ensure_qualified(who: &T::AccountId, column: &Column<T>) -> Result<u32, DispatchError> {
+			let level = Self::current_experience(who, column.exp_type)
+				.map(|experience| experience.level)
+				.unwrap_or_default();
+			ensure!(level >= column.min_level, Error::<T>::ApproverLevelTooLow);
+			Ok(level)
+		}
+
+		pub fn store_interaction(
+			upi: InteractionIdentifier<T>,
+			metadata: InteractionMetadata<T>,
+		) -> DispatchResult {
 			if Interaction::<T>::contains_key(&upi) {
 				return Err(Error::<T>::InteractionExisting.into())
 			}
-			Interaction::<T>::insert(&upi, ());
+			Interaction::<T>::insert(&upi, metadata);
+			InteractionsByBoard::<T>::insert(upi.board_id, &upi, ());
+			InteractionsByWorker::<T>::insert(&upi.worker, &upi, ());
 			Ok(())
 		}
+		/// Appends the interaction to the history of both the worker and the approver
+		fn record_history(
+			approver: &T::AccountId,
+			worker: &T::AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			dst_state: ColumnIndex,
+		) {
+			let entry = |counterpart: &T::AccountId| HistoryEntry::<T> {
+				counterpart: counterpart.clone(),
+				board_id,
+				task_id,
+				dst_state,
+				block: frame_system::Pallet::<T>::block_number(),
+				moment: T::Time::now(),
+			};
+			WorkerHistory::<T>::mutate(worker, |history| {
+				Self::push_evicting(history, entry(approver))
+			});
+			ApproverHistory::<T>::mutate(approver, |history| {
+				Self::push_evicting(history, entry(worker))
+			});
+		}
+
+		/// Pushes `entry` at the end of `history`, evicting the oldest entries if it is full
+		fn push_evicting(
+			history: &mut BoundedVec<HistoryEntry<T>, T::MaxPositiveUserInteractions>,
+			entry: HistoryEntry<T>,
+		) {
+			let max = T::MaxPositiveUserInteractions::get() as usize;
+			if max == 0 {
+				return
+			}
+			while history.len() >= max {
+				history.remove(0);
+			}
+			// Can not fail, there is room for at least one more entry
+			let _ = history.try_push(entry);
+		}
+
+		/// Records an approval of `worker` by `approver` in the current block, returns how many
+		/// earlier approvals of the pair happened within `PairWindow`
+		fn record_pair_interaction(approver: &T::AccountId, worker: &T::AccountId) -> u32 {
+			let now = frame_system::Pallet::<T>::block_number();
+			let window_start = now.saturating_sub(T::PairWindow::get());
+			PairInteractions::<T>::mutate(approver, worker, |blocks| {
+				blocks.retain(|block| *block > window_start);
+				let repetitions = blocks.len() as u32;
+				if repetitions >= T::MaxPairRepetitions::get() && !blocks.is_empty() {
+					blocks.remove(0);
+				}
+				// Only fails when `MaxPairRepetitions` is zero, repetitions are not tracked then
+				let _ = blocks.try_push(now);
+				repetitions
+			})
+		}
+
 		/// Creates a new user experience, based on experience type and user id
 		/// Returns an error if the user already has experience
 		// May add "ensure_signed(origin)?" later on
 		pub fn create_user_experience(
 			user: T::AccountId,
-			exp_type: ExperienceType,
+			exp_type: ExperienceTypeId,
 		) -> DispatchResult {
 			// Check if the user already has experience
 			if ExperienceStorage::<T>::contains_key((&user, &exp_type)) {
@@ -244,37 +2254,795 @@ pub mod pallet {
 				account_id: user.clone(),
 				experience: 0,
 				level: 0,
-				experience_to_next_level: T::BaseExperience::get(),
+				experience_to_next_level: Self::calculate_exp_to_next_level(0),
 			};
 
 			// Store the new user experience
-			ExperienceStorage::<T>::set((user, &exp_type), Some(new_user_exp));
+			ExperienceStorage::<T>::set((&user, &exp_type), Some(new_user_exp));
+			Self::index_level(&user, exp_type, None, 0);
+			Self::deposit_event(Event::ExperienceCreated { who: user, exp_type });
 			Ok(())
 		}
 
 		/// Takes in a user id and returns the user's experience if it exists, otherwise returns an
-		/// error
+		/// error. The decay pending since the last update is already applied
 		pub fn get_user_experience(
 			user: T::AccountId,
-			exp_type: ExperienceType,
+			exp_type: ExperienceTypeId,
 		) -> Result<UserExperience<T>, Error<T>> {
-			ExperienceStorage::<T>::get((user, &exp_type))
-				.ok_or(Error::<T>::UserExperienceDoesNotExist)
+			Self::current_experience(&user, exp_type).ok_or(Error::<T>::UserExperienceDoesNotExist)
 		}
 
-		fn update_user_experience(
+		/// The stored experience of `who` with the decay pending since its last update applied,
+		/// without writing anything
+		fn current_experience(
+			who: &T::AccountId,
+			exp_type: ExperienceTypeId,
+		) -> Option<UserExperience<T>> {
+			let mut experience = ExperienceStorage::<T>::get((who, exp_type))?;
+			let decayed = Self::pending_decay(who, exp_type, experience.experience);
+			if decayed != experience.experience {
+				let (level, experience_to_next_level) = Self::calculate_level(decayed);
+				experience.experience = decayed;
+				experience.level = level;
+				experience.experience_to_next_level = experience_to_next_level;
+			}
+			Some(experience)
+		}
+
+		/// What is left of `experience`, the stored experience of `who`, once the decay pending
+		/// since its last gain is applied
+		fn pending_decay(who: &T::AccountId, exp_type: ExperienceTypeId, experience: u128) -> u128 {
+			match ExperienceDecayedAt::<T>::get((who, exp_type)) {
+				Some((since, anchored)) => {
+					let now = frame_system::Pallet::<T>::block_number();
+					Self::decay_experience(anchored, now.saturating_sub(since)).min(experience)
+				},
+				None => experience,
+			}
+		}
+
+		/// What is left of `experience` after `elapsed` blocks without activity: it halves every
+		/// `ExperienceHalfLife` blocks, the loss being interpolated linearly between two halvings
+		pub fn decay_experience(experience: u128, elapsed: BlockNumberFor<T>) -> u128 {
+			let half_life = match T::ExperienceHalfLife::get() {
+				Some(half_life) if !half_life.is_zero() => half_life.saturated_into::<u128>(),
+				_ => return experience,
+			};
+			let elapsed = elapsed.saturated_into::<u128>();
+			let halvings = (elapsed / half_life).saturated_into::<u32>();
+			let halved = experience.checked_shr(halvings).unwrap_or_default();
+			let progress = Perbill::from_rational(elapsed % half_life, half_life.saturating_mul(2));
+			halved.saturating_sub(progress.mul_floor(halved))
+		}
+
+		/// Stores the decay pending on the experience of `who` in `exp_type`, announcing what
+		/// was lost. Unless `force`, it is only stored once it costs a level, the one the jurors
+		/// are drawn by, reads applying the pending decay anyway
+		pub(crate) fn settle_decay(who: &T::AccountId, exp_type: ExperienceTypeId, force: bool) {
+			if T::ExperienceHalfLife::get().is_none() {
+				return
+			}
+			let key = (who.clone(), exp_type);
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut experience = match ExperienceStorage::<T>::get(&key) {
+				Some(experience) => experience,
+				None => return,
+			};
+			if !ExperienceDecayedAt::<T>::contains_key(&key) {
+				ExperienceDecayedAt::<T>::insert(&key, (now, experience.experience));
+				return
+			}
+			let decayed = Self::pending_decay(who, exp_type, experience.experience);
+			let lost = experience.experience.saturating_sub(decayed);
+			if lost == 0 {
+				return
+			}
+			let old_level = experience.level;
+			let (level, experience_to_next_level) = Self::calculate_level(decayed);
+			if !force && level == old_level {
+				return
+			}
+			experience.experience = decayed;
+			experience.level = level;
+			experience.experience_to_next_level = experience_to_next_level;
+			ExperienceStorage::<T>::insert(&key, experience);
+			Self::index_level(who, exp_type, Some(old_level), level);
+			Self::deposit_event(Event::ExperienceDecayed {
+				who: who.clone(),
+				exp_type,
+				lost,
+				level,
+			});
+		}
+
+		/// Settles the decay of up to `limit` user experiences, starting after the ones settled
+		/// by the previous sweep. Returns how many were visited
+		pub fn sweep_decay(limit: u32) -> u32 {
+			let mut last = DecaySweepCursor::<T>::take();
+			let mut keys = match &last {
+				Some(key) => ExperienceStorage::<T>::iter_keys_from(
+					ExperienceStorage::<T>::hashed_key_for(key),
+				),
+				None => ExperienceStorage::<T>::iter_keys(),
+			};
+			let mut swept = 0;
+			while swept < limit {
+				match keys.next() {
+					Some((who, exp_type)) => {
+						Self::settle_decay(&who, exp_type, false);
+						last = Some((who, exp_type));
+					},
+					// Every experience has been visited, the next sweep starts over
+					None => return swept,
+				}
+				swept += 1;
+			}
+			DecaySweepCursor::<T>::set(last);
+			swept
+		}
+
+		/// Removes the entries of `PairInteractions` without any approval within `PairWindow`,
+		/// visiting up to `limit` entries starting after the ones visited by the previous sweep.
+		/// Returns how many were visited
+		pub fn sweep_pairs(limit: u32) -> u32 {
+			let now = frame_system::Pallet::<T>::block_number();
+			let window_start = now.saturating_sub(T::PairWindow::get());
+			let mut last = PairSweepCursor::<T>::take();
+			let mut pairs = match &last {
+				Some((approver, worker)) => PairInteractions::<T>::iter_from(
+					PairInteractions::<T>::hashed_key_for(approver, worker),
+				),
+				None => PairInteractions::<T>::iter(),
+			};
+			let mut swept = 0;
+			while swept < limit {
+				match pairs.next() {
+					Some((approver, worker, blocks)) => {
+						// The blocks are ordered, the last one is the most recent approval
+						if blocks.last().map_or(true, |block| *block <= window_start) {
+							PairInteractions::<T>::remove(&approver, &worker);
+						}
+						last = Some((approver, worker));
+					},
+					// Every pair has been visited, the next sweep starts over
+					None => return swept,
+				}
+				swept += 1;
+			}
+			PairSweepCursor::<T>::set(last);
+			swept
+		}
+
+		/// Prunes up to `limit` entries of `EpochExperience` and `RewardsClaimed` from the epochs
+		/// whose rewards expired, oldest first, then their `EpochRewards` and `EpochTotals` entries,
+		/// which count as two. Returns the number of entries swept
+		pub fn sweep_epochs(limit: u32) -> u32 {
+			let current = CurrentEpoch::<T>::get();
+			let mut epoch = EpochSweepCursor::<T>::get();
+			let mut swept = 0;
+			while swept < limit && !Self::rewards_claimable(epoch, current) {
+				let removed = EpochExperience::<T>::clear_prefix(epoch, limit - swept, None);
+				swept += removed.unique;
+				if removed.maybe_cursor.is_some() {
+					break
+				}
+				let removed = RewardsClaimed::<T>::clear_prefix(epoch, limit - swept, None);
+				swept += removed.unique;
+				if removed.maybe_cursor.is_some() {
+					break
+				}
+				// Both prefixes are empty. What was left of the rewards went back to the pot when
+				// they expired, so their records go too
+				if limit - swept < 2 {
+					break
+				}
+				EpochRewards::<T>::remove(epoch);
+				EpochTotals::<T>::remove(epoch);
+				// The next sweep starts from the next epoch
+				epoch += 1;
+				swept += 2;
+			}
+			EpochSweepCursor::<T>::put(epoch);
+			swept
+		}
+
+		/// Runs `sweep` on as many entries as `remaining_weight` allows, `weight` being its weight
+		/// depending on the number of entries. Returns the weight used
+		fn idle_sweep(
+			remaining_weight: Weight,
+			weight: fn(u32) -> Weight,
+			sweep: fn(u32) -> u32,
+		) -> Weight {
+			let base = weight(0);
+			let per_entry = weight(1).saturating_sub(base);
+			if remaining_weight.any_lt(base) {
+				return Weight::zero()
+			}
+			let limit = remaining_weight
+				.saturating_sub(base)
+				.checked_div_per_component(&per_entry)
+				.unwrap_or_default();
+			if limit == 0 {
+				return Weight::zero()
+			}
+			weight(sweep(limit.saturated_into()))
+		}
+
+		/// Draws `JurySize` jurors among the accounts with at least `JuryMinLevel` in `exp_type`,
+		/// leaving out the `excluded` parties of the dispute. Every eligible account is as likely
+		/// to be drawn: the whole `AccountsByLevel` pool is walked once and every account takes a
+		/// seat with the odds it has in a uniform draw (reservoir sampling), with the randomness
+		/// re-seeded for each of them. Visits at most `candidates` accounts, returns the jury and
+		/// the number of accounts visited
+		pub(crate) fn select_jury(
+			seed: T::Hash,
+			exp_type: ExperienceTypeId,
+			excluded: &[&T::AccountId],
+			candidates: u32,
+		) -> Result<(BoundedVec<T::AccountId, T::JurySize>, u32), DispatchError> {
+			let jury_size = T::JurySize::get() as usize;
+			let min_level = T::JuryMinLevel::get();
+			let mut jury = Vec::with_capacity(jury_size);
+			let mut eligible = 0u64;
+			let mut visited = 0u32;
+			let pool = AccountsByLevel::<T>::iter_prefix_from(
+				(exp_type,),
+				Self::level_key(exp_type, &min_level.to_be_bytes()),
+			);
+			for ((_, who), _) in pool {
+				ensure!(visited < candidates, Error::<T>::TooManyCandidates);
+				visited += 1;
+				// Some accounts may have decayed below `JuryMinLevel` since they were indexed
+				let qualified = Self::current_experience(&who, exp_type)
+					.map_or(false, |experience| experience.level >= min_level);
+				if !qualified || excluded.contains(&&who) {
+					continue
+				}
+				if jury.len() < jury_size {
+					jury.push(who);
+				} else {
+					// Takes one of the seats with odds `jury_size / (eligible + 1)`
+					let random = T::Hashing::hash_of(&(seed, eligible));
+					let draw = u64::decode(&mut random.as_ref()).unwrap_or_default();
+					if let Some(seat) = jury.get_mut((draw % (eligible + 1)) as usize) {
+						*seat = who;
+					}
+				}
+				eligible += 1;
+			}
+			ensure!(jury.len() == jury_size, Error::<T>::NotEnoughJurors);
+			// Can not fail, the jury has `JurySize` jurors
+			let jury = jury.try_into().map_err(|_| Error::<T>::NotEnoughJurors)?;
+			Ok((jury, visited))
+		}
+
+		/// Closes a dispute by the majority of the votes cast, a tie not being a majority.
+		/// A guilty approver is slashed and the experience the interaction credited is taken back
+		/// from the worker, otherwise the deposit of the challenger is slashed
+		fn resolve(dispute_id: DisputeId, dispute: Dispute<T>) -> DispatchResult {
+			let guilty = dispute.votes.iter().filter(|(_, guilty)| *guilty).count();
+			let innocent = dispute.votes.len() - guilty;
+			let verdict = if dispute.votes.is_empty() {
+				Verdict::NoQuorum
+			} else if guilty > innocent {
+				Verdict::Guilty
+			} else {
+				Verdict::Innocent
+			};
+
+			let upi = dispute.interaction;
+			match verdict {
+				Verdict::Guilty => {
+					T::Currency::unreserve(&dispute.challenger, dispute.deposit);
+					let (bond, lock_end) = match Interaction::<T>::get(&upi) {
+						Some(metadata) => {
+							// Always known, migrated interactions can not be challenged
+							let awarded = metadata.awarded.unwrap_or_default();
+							Self::revert_experience(&upi.worker, dispute.exp_type, awarded)?;
+							// Closed epochs are left as they were
+							if metadata.block >= Some(EpochStart::<T>::get()) {
+								Self::forget_epoch_experience(&upi.worker, awarded);
+							}
+							let lock_end = metadata.block.map(Self::lock_end).unwrap_or_default();
+							(metadata.bond, lock_end)
+						},
+						None => Default::default(),
+					};
+					// The revoked work does not earn a share of the bounty of the task anymore
+					TaskContributions::<T>::mutate(upi.board_id, upi.task_id, |contributions| {
+						contributions.retain(|contribution| {
+							contribution.dst_state != upi.dst_state ||
+								contribution.worker != upi.worker
+						})
+					});
+					// The approver loses the bond their approval locked
+					let amount = Self::slash_bond(&upi.approver, bond, lock_end);
+					Self::deposit_event(Event::ApproverSlashed {
+						who: upi.approver.clone(),
+						amount,
+					});
+					DisputedInteractions::<T>::insert(&upi, DisputeStatus::Closed(verdict));
+				},
+				Verdict::Innocent => {
+					let (slashed, _) =
+						T::Currency::slash_reserved(&dispute.challenger, dispute.deposit);
+					T::Slashed::on_unbalanced(slashed);
+					DisputedInteractions::<T>::insert(&upi, DisputeStatus::Closed(verdict));
+				},
+				Verdict::NoQuorum => {
+					T::Currency::unreserve(&dispute.challenger, dispute.deposit);
+					DisputedInteractions::<T>::remove(&upi);
+				},
+			}
+			Disputes::<T>::remove(dispute_id);
+
+			Self::deposit_event(Event::DisputeResolved { dispute_id, verdict });
+			Ok(())
+		}
+
+		/// Closes the current epoch at block `now` and starts the next one. The reward pot,
+		/// topped up by `InflationPerEpoch`, goes to the contributors of the epoch if there are
+		/// any. What is left unclaimed of the rewards expiring with it goes back to the pot
+		pub(crate) fn close_epoch(now: T::BlockNumber) {
+			let epoch = CurrentEpoch::<T>::get();
+			let totals = EpochTotals::<T>::get(epoch);
+			let minted =
+				T::Currency::deposit_creating(&Self::reward_account(), T::InflationPerEpoch::get());
+			let mut pot = RewardPot::<T>::get().saturating_add(minted.peek());
+			let rewards = if totals.experience > 0 {
+				EpochRewards::<T>::insert(epoch, EpochReward { total: pot, unclaimed: pot });
+				sp_std::mem::take(&mut pot)
+			} else {
+				Zero::zero()
+			};
+			// Once the next epoch started, the rewards of `expired` can not be claimed anymore
+			if let Some(expired) = epoch.checked_sub(T::RewardClaimEpochs::get()) {
+				let mut expired_rewards = EpochRewards::<T>::get(expired);
+				let amount = sp_std::mem::take(&mut expired_rewards.unclaimed);
+				if !amount.is_zero() {
+					EpochRewards::<T>::insert(expired, expired_rewards);
+					pot = pot.saturating_add(amount);
+					Self::deposit_event(Event::RewardsExpired { epoch: expired, amount });
+				}
+			}
+			RewardPot::<T>::put(pot);
+			CurrentEpoch::<T>::put(epoch.saturating_add(1));
+			EpochStart::<T>::put(now);
+			Self::deposit_event(Event::EpochClosed {
+				epoch,
+				experience: totals.experience,
+				contributors: totals.contributors,
+				rewards,
+			});
+		}
+
+		/// Tallies `amount` experience earned by `who` through an interaction in the current
+		/// epoch, returns what is left of it once capped by `MaxExperiencePerEpoch`
+		fn record_epoch_experience(who: &T::AccountId, amount: u128) -> u128 {
+			let epoch = CurrentEpoch::<T>::get();
+			let earned = EpochExperience::<T>::get(epoch, who);
+			let amount = match T::MaxExperiencePerEpoch::get() {
+				Some(cap) => amount.min(cap.saturating_sub(earned)),
+				None => amount,
+			};
+			if amount == 0 {
+				return 0
+			}
+			EpochExperience::<T>::insert(epoch, who, earned.saturating_add(amount));
+			EpochTotals::<T>::mutate(epoch, |totals| {
+				totals.experience = totals.experience.saturating_add(amount);
+				if earned == 0 {
+					totals.contributors = totals.contributors.saturating_add(1);
+				}
+			});
+			amount
+		}
+
+		/// Takes back from the tally of the current epoch `amount` experience `who` earned
+		/// through a revoked interaction
+		fn forget_epoch_experience(who: &T::AccountId, amount: u128) {
+			let epoch = CurrentEpoch::<T>::get();
+			let earned = EpochExperience::<T>::get(epoch, who);
+			if earned == 0 {
+				return
+			}
+			let amount = amount.min(earned);
+			let left = earned - amount;
+			if left == 0 {
+				EpochExperience::<T>::remove(epoch, who);
+			} else {
+				EpochExperience::<T>::insert(epoch, who, left);
+			}
+			EpochTotals::<T>::mutate(epoch, |totals| {
+				totals.experience = totals.experience.saturating_sub(amount);
+				if left == 0 {
+					totals.contributors = totals.contributors.saturating_sub(1);
+				}
+			});
+		}
+
+		/// The account holding the task bounties in escrow. It is kept alive by the existential
+		/// deposit it was funded with at genesis
+		pub fn bounty_account() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"bnty")
+		}
+
+		/// The account holding the reward pot and the rewards of the closed epochs not claimed
+		/// yet. It is kept alive by the existential deposit it was funded with at genesis
+		pub fn reward_account() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"rwrd")
+		}
+
+		/// Funds the bounty and the reward accounts with the existential deposit, so that paying
+		/// out everything they hold for the pallet never reaps them
+		pub(crate) fn fund_pallet_accounts() {
+			let deposit = T::Currency::minimum_balance();
+			for account in [Self::bounty_account(), Self::reward_account()] {
+				if T::Currency::free_balance(&account) < deposit {
+					drop(T::Currency::deposit_creating(&account, deposit));
+				}
+			}
+		}
+
+		/// Whether the rewards of the closed `epoch` may still be claimed while `current` is the
+		/// current epoch
+		pub(crate) fn rewards_claimable(epoch: EpochId, current: EpochId) -> bool {
+			current.saturating_sub(epoch) <= T::RewardClaimEpochs::get()
+		}
+
+		/// Pays the bounty of a task that reached the last column to the workers whose work moved
+		/// it there, in proportion to what their approved work was worth before the repetition
+		/// decay and the epoch cap, or evenly if it was worth nothing. The last worker also gets
+		/// what rounding left. The funder is only refunded what could not be paid, i.e. when all
+		/// the work was revoked or a share is below the existential deposit of a new account
+		fn release_bounty(board_id: BoardId, task_id: TaskId) {
+			let contributions = TaskContributions::<T>::take(board_id, task_id);
+			let bounty = match Bounties::<T>::take(board_id, task_id) {
+				Some(bounty) => bounty,
+				None => return,
+			};
+			// The share of every worker, in the order they first worked on the task
+			let mut shares = Vec::<(T::AccountId, u128)>::new();
+			for Contribution { worker, granted, .. } in contributions {
+				match shares.iter_mut().find(|(who, _)| *who == worker) {
+					Some((_, share)) => *share = share.saturating_add(granted),
+					None => shares.push((worker, granted)),
+				}
+			}
+			let mut total =
+				shares.iter().fold(0u128, |total, (_, share)| total.saturating_add(*share));
+			if total == 0 {
+				shares.iter_mut().for_each(|(_, share)| *share = 1);
+				total = shares.len() as u128;
+			}
+
+			let escrow = Self::bounty_account();
+			let last = shares.len().saturating_sub(1);
+			let mut left = bounty.amount;
+			for (index, (worker, share)) in shares.into_iter().enumerate() {
+				let amount = if index == last {
+					left
+				} else {
+					Perbill::from_rational(share, total).mul_floor(bounty.amount)
+				};
+				if amount.is_zero() ||
+					T::Currency::transfer(&escrow, &worker, amount, KeepAlive).is_err()
+				{
+					continue
+				}
+				left = left.saturating_sub(amount);
+				Self::deposit_event(Event::BountyPaid { board_id, task_id, worker, amount });
+			}
+			if !left.is_zero() &&
+				T::Currency::transfer(&escrow, &bounty.funder, left, KeepAlive).is_ok()
+			{
+				Self::deposit_event(Event::BountyRefunded {
+					board_id,
+					task_id,
+					funder: bounty.funder,
+					amount: left,
+				});
+			}
+		}
+
+		/// The bond backing approvals worth `granted` experience
+		pub(crate) fn required_bond(granted: u128) -> BalanceOf<T> {
+			T::BondPerExperience::get().saturating_mul(granted.saturated_into())
+		}
+
+		/// The block from which the bond locked by an approval made at `approved` is released,
+		/// once the approval can no longer be challenged nor its dispute be voted on
+		pub(crate) fn lock_end(approved: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			approved
+				.saturating_add(T::ChallengePeriod::get())
+				.saturating_add(T::VotingPeriod::get())
+		}
+
+		/// Slashes up to `amount` of the bond of `who`, the active part first then the one still
+		/// unbonding, and hands it to `Slashed`. What is slashed from the active part is taken
+		/// out of the lock ending at `lock_end`. Returns the amount slashed
+		pub fn slash_bond(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			lock_end: BlockNumberFor<T>,
+		) -> BalanceOf<T> {
+			let mut bond = Bonds::<T>::get(who);
+			let from_active = amount.min(bond.active);
+			let from_unbonding = amount.saturating_sub(from_active).min(bond.unbonding);
+			bond.active -= from_active;
+			bond.unbonding -= from_unbonding;
+			// The slashed bond does not back any approval anymore
+			bond.unlock(from_active, lock_end);
+			let (slashed, missing) =
+				T::Currency::slash_reserved(who, from_active.saturating_add(from_unbonding));
+			T::Slashed::on_unbalanced(slashed);
+			Self::store_bond(who, bond);
+			from_active.saturating_add(from_unbonding).saturating_sub(missing)
+		}
+
+		/// Stores the bond of `who`, removing it once nothing is reserved anymore
+		fn store_bond(who: &T::AccountId, bond: Bond<T>) {
+			if bond.active.is_zero() && bond.unbonding.is_zero() {
+				Bonds::<T>::remove(who);
+			} else {
+				Bonds::<T>::insert(who, bond);
+			}
+		}
+
+		/// Takes back up to `amount` experience of `exp_type` from `who`, recomputing their level
+		pub(crate) fn revert_experience(
+			who: &T::AccountId,
+			exp_type: ExperienceTypeId,
+			amount: u128,
+		) -> DispatchResult {
+			Self::settle_decay(who, exp_type, true);
+			let mut experience = match ExperienceStorage::<T>::get((who, exp_type)) {
+				Some(experience) => experience,
+				None => return Ok(()),
+			};
+			// Part of the experience may have decayed already
+			let amount = amount.min(experience.experience);
+			// The experience the decay is counted from shrinks in proportion, so that it goes on
+			// decaying on the same schedule
+			ExperienceDecayedAt::<T>::mutate_exists((who, exp_type), |anchor| {
+				if let Some((_, anchored)) = anchor {
+					let left = experience.experience - amount;
+					*anchored =
+						Perbill::from_rational(left, experience.experience).mul_floor(*anchored);
+				}
+			});
+			experience.experience -= amount;
+			let (level, _) = Self::calculate_level(experience.experience);
+			Self::update_user_experience(who.clone(), exp_type, experience)?;
+			Self::deposit_event(Event::ExperienceReverted {
+				who: who.clone(),
+				exp_type,
+				amount,
+				level,
+			});
+			Ok(())
+		}
+
+		/// Credits `amount` experience of `exp_type` to the user, creating the user experience
+		/// first if the user has none of that type yet
+		pub fn add_user_experience(
 			user: T::AccountId,
-			experience: UserExperience<T>,
+			exp_type: ExperienceTypeId,
+			amount: u128,
 		) -> DispatchResult {
-			unimplemented!()
+			if !ExperienceStorage::<T>::contains_key((&user, &exp_type)) {
+				Self::create_user_experience(user.clone(), exp_type)?;
+			}
+			if amount == 0 {
+				return Ok(())
+			}
+			// The experience decayed while the user was inactive, only then the new one counts
+			Self::settle_decay(&user, exp_type, true);
+			let mut experience = ExperienceStorage::<T>::get((&user, exp_type))
+				.ok_or(Error::<T>::UserExperienceDoesNotExist)?;
+			experience.experience = experience.experience.saturating_add(amount);
+			let gained = experience.experience;
+			Self::update_user_experience(user.clone(), exp_type, experience)?;
+			if T::ExperienceHalfLife::get().is_some() {
+				let now = frame_system::Pallet::<T>::block_number();
+				ExperienceDecayedAt::<T>::insert((&user, exp_type), (now, gained));
+			}
+			Self::deposit_event(Event::ExperienceGained { who: user, exp_type, amount });
+			Ok(())
 		}
 
-		/// Usees our Config types to calculate the amount of experience required to level up
-		fn calculate_exp_to_next_level(
-			experience: UserExperience<T>,
-			level: u32,
+		/// Recomputes `level` and `experience_to_next_level` from the accumulated experience and
+		/// stores the result for the given experience type, announcing any level up
+		fn update_user_experience(
+			user: T::AccountId,
+			exp_type: ExperienceTypeId,
+			mut experience: UserExperience<T>,
 		) -> DispatchResult {
-			unimplemented!()
+			let old_level = experience.level;
+			let (level, experience_to_next_level) = Self::calculate_level(experience.experience);
+			experience.level = level;
+			experience.experience_to_next_level = experience_to_next_level;
+			ExperienceStorage::<T>::insert((&user, exp_type), experience);
+			Self::index_level(&user, exp_type, Some(old_level), level);
+			if level > old_level {
+				Self::deposit_event(Event::LeveledUp { who: user, exp_type, new_level: level });
+			}
+			Ok(())
+		}
+
+		/// Moves `who` from `old_level` to `new_level` in the `AccountsByLevel` index of `exp_type`
+		pub(crate) fn index_level(
+			who: &T::AccountId,
+			exp_type: ExperienceTypeId,
+			old_level: Option<u32>,
+			new_level: u32,
+		) {
+			if old_level == Some(new_level) {
+				return
+			}
+			if let Some(old_level) = old_level {
+				AccountsByLevel::<T>::remove((exp_type, old_level.to_be_bytes(), who));
+			}
+			AccountsByLevel::<T>::insert((exp_type, new_level.to_be_bytes(), who), ());
+		}
+
+		/// Returns the level reached with `experience` together with the experience still
+		/// missing to reach the following level
+		pub fn calculate_level(experience: u128) -> (u32, u128) {
+			level_from_experience::<T::LevelCurve>(experience, T::MaxLevel::get())
+		}
+
+		/// The experience of `who` in `exp_type`, `None` if they never earned any
+		pub fn user_experience(
+			who: T::AccountId,
+			exp_type: ExperienceTypeId,
+		) -> Option<ExperienceInfo> {
+			Self::current_experience(&who, exp_type).map(Into::into)
+		}
+
+		/// The experience of `who` in every registered experience type they earned some of
+		pub fn user_profile(who: T::AccountId) -> Vec<(ExperienceTypeId, ExperienceInfo)> {
+			ExperienceTypes::<T>::iter_keys()
+				.filter_map(|exp_type| {
+					Self::user_experience(who.clone(), exp_type).map(|info| (exp_type, info))
+				})
+				.collect()
+		}
+
+		/// The experience required to level up from every level below `MaxLevel`, the first
+		/// item being the experience needed to reach level 1
+		pub fn level_thresholds() -> Vec<u128> {
+			(0..T::MaxLevel::get()).map(T::LevelCurve::experience_for_next_level).collect()
+		}
+
+		/// Up to `limit` interactions that happened on `board_id`, starting after `cursor`
+		pub fn interactions_by_board(
+			board_id: BoardId,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> InteractionPage<T::AccountId> {
+			let interactions = match cursor {
+				Some(cursor) => InteractionsByBoard::<T>::iter_prefix_from(board_id, cursor),
+				None => InteractionsByBoard::<T>::iter_prefix(board_id),
+			};
+			Self::interaction_page(interactions, limit)
+		}
+
+		/// Up to `limit` interactions on the work of `worker`, starting after `cursor`
+		pub fn interactions_by_worker(
+			worker: T::AccountId,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> InteractionPage<T::AccountId> {
+			let interactions = match cursor {
+				Some(cursor) => InteractionsByWorker::<T>::iter_prefix_from(worker, cursor),
+				None => InteractionsByWorker::<T>::iter_prefix(worker),
+			};
+			Self::interaction_page(interactions, limit)
+		}
+
+		/// Takes a page of at most `MaxPageSize` interactions out of an index. The cursor is the
+		/// raw storage key of the last interaction of the page
+		fn interaction_page(
+			mut interactions: PrefixIterator<(InteractionIdentifier<T>, ())>,
+			limit: u32,
+		) -> InteractionPage<T::AccountId> {
+			let limit = limit.min(T::MaxPageSize::get()) as usize;
+			let page = interactions
+				.by_ref()
+				.take(limit)
+				.map(|(upi, _)| upi.into())
+				.collect::<Vec<InteractionInfo<T::AccountId>>>();
+			let next_cursor =
+				(limit > 0 && page.len() == limit).then(|| interactions.last_raw_key().to_vec());
+			InteractionPage { interactions: page, next_cursor }
+		}
+
+		/// Up to `limit` accounts with at least `min_level` in `exp_type` by ascending level,
+		/// starting after `cursor`. Accounts whose level dropped below `min_level` because of
+		/// pending decay are skipped, so a page may hold fewer than `limit` accounts
+		pub fn accounts_by_level(
+			exp_type: ExperienceTypeId,
+			min_level: u32,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> LevelPage<T::AccountId> {
+			// Every key of the first bucket to return starts with this one, so comes after it
+			let start =
+				cursor.unwrap_or_else(|| Self::level_key(exp_type, &min_level.to_be_bytes()));
+			let limit = limit.min(T::MaxPageSize::get()) as usize;
+			let mut entries = AccountsByLevel::<T>::iter_prefix_from((exp_type,), start);
+			let mut visited = 0;
+			let mut accounts = Vec::new();
+			for ((_, who), _) in entries.by_ref().take(limit) {
+				visited += 1;
+				if let Some(experience) = Self::current_experience(&who, exp_type) {
+					if experience.level >= min_level {
+						accounts.push((who, experience.level));
+					}
+				}
+			}
+			let next_cursor =
+				(limit > 0 && visited == limit).then(|| entries.last_raw_key().to_vec());
+			LevelPage { accounts, next_cursor }
+		}
+
+		/// The raw key of `AccountsByLevel` under `exp_type` continued by `suffix`
+		fn level_key(exp_type: ExperienceTypeId, suffix: &[u8]) -> Vec<u8> {
+			let mut key = AccountsByLevel::<T>::final_prefix().to_vec();
+			key.extend(Twox64Concat::hash(&exp_type.encode()));
+			key.extend(suffix);
+			key
+		}
+
+		/// The evidence attached to a recorded interaction, `None` if there is none
+		pub fn interaction_evidence(
+			approver: T::AccountId,
+			worker: T::AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			src_state: ColumnIndex,
+			dst_state: ColumnIndex,
+		) -> Option<EvidenceInfo> {
+			let upi = InteractionIdentifier::<T> {
+				approver,
+				worker,
+				board_id,
+				task_id,
+				src_state,
+				dst_state,
+			};
+			InteractionEvidence::<T>::get(upi).map(Into::into)
+		}
+
+		/// Whether `approver` already recorded this positive interaction on the work of `worker`
+		pub fn interaction_exists(
+			approver: T::AccountId,
+			worker: T::AccountId,
+			board_id: BoardId,
+			task_id: TaskId,
+			src_state: ColumnIndex,
+			dst_state: ColumnIndex,
+		) -> bool {
+			let upi = InteractionIdentifier::<T> {
+				approver,
+				worker,
+				board_id,
+				task_id,
+				src_state,
+				dst_state,
+			};
+			Interaction::<T>::contains_key(upi)
+		}
+
+		/// Uses our Config types to calculate the amount of experience required to level up
+		/// from `level` to `level + 1`, zero once `MaxLevel` is reached
+		pub fn calculate_exp_to_next_level(level: u32) -> u128 {
+			if level >= T::MaxLevel::get() {
+				return 0
+			}
+			T::LevelCurve::experience_for_next_level(level)
 		}
 	}
 }
@@ -0,0 +1,197 @@
+//! Storage migrations of the popi pallet.
+//!
+//! Add them to the `Executive` of the runtime, they only run when the on-chain storage version
+//! of the pallet is the one they migrate from.
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout of the first runtime, when the experience types were a fixed enum and the
+/// interactions recorded neither their columns nor anything about them
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+	/// The hardcoded experience types. Each variant is registered with its index as id
+	pub enum ExperienceType {
+		Frontend,
+		Backend,
+		Marketing,
+		GraphicDesign,
+	}
+
+	impl ExperienceType {
+		/// Every variant, ordered by index
+		pub const ALL: [ExperienceType; 4] = [
+			ExperienceType::Frontend,
+			ExperienceType::Backend,
+			ExperienceType::Marketing,
+			ExperienceType::GraphicDesign,
+		];
+
+		/// The id of the variant in the `ExperienceTypes` registry
+		pub fn id(self) -> ExperienceTypeId {
+			self as ExperienceTypeId
+		}
+
+		/// The name the variant is registered with
+		pub fn name(self) -> &'static [u8] {
+			match self {
+				ExperienceType::Frontend => b"Frontend",
+				ExperienceType::Backend => b"Backend",
+				ExperienceType::Marketing => b"Marketing",
+				ExperienceType::GraphicDesign => b"GraphicDesign",
+			}
+		}
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Clone, Copy, PartialEq, Eq)]
+	#[scale_info(skip_type_params(T))]
+	/// The interaction identifier of the first runtime, without the columns
+	pub struct InteractionIdentifier<T: Config> {
+		pub approver: T::AccountId,
+		pub worker: T::AccountId,
+		pub board_id: u32,
+		pub task_id: u32,
+	}
+
+	#[storage_alias]
+	pub type ExperienceStorage<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(<T as frame_system::Config>::AccountId, ExperienceType),
+		UserExperience<T>,
+	>;
+
+	#[storage_alias]
+	pub type Interaction<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, InteractionIdentifier<T>, ()>;
+
+	/// The value of the pallet template the first runtime still declared
+	#[storage_alias]
+	pub type Something<T: Config> = StorageValue<Pallet<T>, u32>;
+}
+
+/// Upgrades the storage of the first runtime to the current layout
+pub mod v1 {
+	use super::*;
+
+	/// Migrates the storage of the first runtime:
+	///
+	/// - the old enum variants are registered as experience types 0 to 3, `ExperienceStorage` is
+	///   re-keyed with their ids and every user experience is indexed in `AccountsByLevel`, its
+	///   level being recomputed with `LevelCurve`. They start decaying from their next update
+	/// - the interactions are re-keyed with both columns set to 0, which no transition recorded
+	///   since uses, and indexed by board and by worker. The columns can not be recovered, nor
+	///   the block, the moment and the experience credited, which are left to `None` and keep
+	///   these interactions from being challenged
+	/// - the bounty and the reward accounts are funded with the existential deposit
+	/// - the value of the pallet template is removed
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			v0::Something::<T>::kill();
+			for exp_type in v0::ExperienceType::ALL {
+				let info = ExperienceTypeInfo::<T> {
+					name: BoundedVec::truncate_from(exp_type.name().to_vec()),
+					active: true,
+				};
+				ExperienceTypes::<T>::insert(exp_type.id(), info);
+			}
+			NextExperienceTypeId::<T>::put(v0::ExperienceType::ALL.len() as ExperienceTypeId);
+
+			// The new keys live under the same prefix, collect the old entries before inserting
+			let experiences = v0::ExperienceStorage::<T>::drain().collect::<Vec<_>>();
+			let migrated_experiences = experiences.len() as u64;
+			for ((who, exp_type), mut experience) in experiences {
+				let (level, experience_to_next_level) =
+					Pallet::<T>::calculate_level(experience.experience);
+				experience.level = level;
+				experience.experience_to_next_level = experience_to_next_level;
+				ExperienceStorage::<T>::insert((&who, exp_type.id()), experience);
+				Pallet::<T>::index_level(&who, exp_type.id(), None, level);
+			}
+
+			let interactions = v0::Interaction::<T>::drain().collect::<Vec<_>>();
+			let migrated_interactions = interactions.len() as u64;
+			for (legacy, ()) in interactions {
+				let upi = InteractionIdentifier::<T> {
+					approver: legacy.approver,
+					worker: legacy.worker,
+					board_id: legacy.board_id,
+					task_id: legacy.task_id,
+					src_state: 0,
+					dst_state: 0,
+				};
+				let metadata = InteractionMetadata::<T> {
+					block: None,
+					moment: None,
+					src_state: 0,
+					dst_state: 0,
+					exp_type: None,
+					awarded: None,
+					bond: Default::default(),
+					evidence: None,
+				};
+				InteractionsByBoard::<T>::insert(upi.board_id, &upi, ());
+				InteractionsByWorker::<T>::insert(&upi.worker, &upi, ());
+				Interaction::<T>::insert(upi, metadata);
+			}
+
+			Pallet::<T>::fund_pallet_accounts();
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			// The pallet accounts and the total issuance, on top of the version, the value of the
+			// template and the registry
+			T::DbWeight::get().reads_writes(
+				4 + migrated_experiences + migrated_interactions,
+				11 + 3 * migrated_experiences + 4 * migrated_interactions,
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let experiences = v0::ExperienceStorage::<T>::iter_keys().count() as u64;
+			let interactions = v0::Interaction::<T>::iter_keys().count() as u64;
+			Ok((experiences, interactions).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (experiences, interactions) = <(u64, u64)>::decode(&mut &state[..])
+				.map_err(|_| "the pre_upgrade state can not be decoded")?;
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "the storage version was not bumped");
+			ensure!(
+				ExperienceStorage::<T>::iter().count() as u64 == experiences,
+				"some user experiences were lost"
+			);
+			ensure!(
+				AccountsByLevel::<T>::iter_keys().count() as u64 == experiences,
+				"some user experiences are not indexed by level"
+			);
+			ensure!(
+				Interaction::<T>::iter().count() as u64 == interactions,
+				"some interactions were lost"
+			);
+			ensure!(
+				InteractionsByBoard::<T>::iter_keys().count() as u64 == interactions,
+				"some interactions are not indexed by board"
+			);
+			ensure!(
+				InteractionsByWorker::<T>::iter_keys().count() as u64 == interactions,
+				"some interactions are not indexed by worker"
+			);
+			ensure!(!v0::Something::<T>::exists(), "the value of the template was left");
+			Ok(())
+		}
+	}
+}
@@ -1,9 +1,14 @@
 use crate as pallet_popi;
-use frame_support::traits::{ConstU16, ConstU64};
-use sp_core::{H256, ConstU128, ConstU32};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU64, GenesisBuild, Randomness},
+	PalletId,
+};
+use sp_core::{ConstU128, ConstU32, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	Percent,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -17,6 +22,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
 		Popi: pallet_popi,
 	}
 );
@@ -32,14 +39,14 @@ impl frame_system::Config for Test {
 	type BlockNumber = u64;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
-	type AccountId = u64;
+	type AccountId = u128;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -48,15 +55,118 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<EXISTENTIAL_DEPOSIT>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type HoldIdentifier = ();
+	type MaxHolds = ();
+}
+
+/// Deterministic randomness: the hash of the subject, so that a jury draw can be reproduced
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
+}
+
+parameter_types! {
+	/// Every level of the approver adds 1 to the 10 experience of an interaction
+	pub const LevelBonus: Percent = Percent::from_percent(10);
+	/// Experience does not decay unless a test enables it
+	pub storage ExperienceHalfLife: Option<u64> = None;
+	/// Approvals need no bond unless a test requires one
+	pub storage BondPerExperience: u64 = 0;
+	/// Nobody's experience is capped unless a test sets a cap
+	pub storage MaxExperiencePerEpoch: Option<u128> = None;
+	/// Nothing is minted into the reward pot unless a test enables it
+	pub storage InflationPerEpoch: u64 = 0;
+	pub const PopiPalletId: PalletId = PalletId(*b"py/popi_");
+}
+
 impl pallet_popi::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
-	type BaseExperience = ConstU128<100>;
-	type LevelDifficulty = ConstU32<10>;
-	type DifficultyMultiplier = ConstU32<2>;
+	type LevelCurve = pallet_popi::ExponentialCurve<ConstU128<100>, ConstU32<2>, ConstU32<1>>;
+	type MaxLevel = ConstU32<20>;
+	type ExperiencePerInteraction = ConstU128<10>;
+	type AwardWeighting = pallet_popi::LevelBonusWeighting<LevelBonus>;
+	type RepetitionDecay = pallet_popi::HalvingDecay;
+	type PairWindow = ConstU64<100>;
+	type MaxPairRepetitions = ConstU32<4>;
+	type MaxNameLength = ConstU32<32>;
+	type MaxColumns = ConstU32<10>;
+	type ExperienceTypeAdminOrigin = frame_system::EnsureRoot<u128>;
+	type MaxPositiveUserInteractions = ConstU32<3>;
+	type Time = Timestamp;
+	type MaxPageSize = ConstU32<10>;
+	type MaxEvidence = ConstU32<2>;
+	type ExperienceHalfLife = ExperienceHalfLife;
+	type Currency = Balances;
+	type BondPerExperience = BondPerExperience;
+	type UnbondingPeriod = ConstU64<20>;
+	type Randomness = TestRandomness;
+	type Slashed = ();
+	type ChallengeDeposit = ConstU64<50>;
+	type ChallengePeriod = ConstU64<30>;
+	type MaxBondLocks = ConstU32<4>;
+	type JurySize = ConstU32<3>;
+	type JuryMinLevel = ConstU32<2>;
+	type VotingPeriod = ConstU64<10>;
+	type PalletId = PopiPalletId;
+	type EpochLength = ConstU64<100>;
+	type MaxExperiencePerEpoch = MaxExperiencePerEpoch;
+	type InflationPerEpoch = InflationPerEpoch;
+	type RewardClaimEpochs = ConstU32<2>;
 }
 
+/// The least balance an account may hold, the bounty and reward accounts start with it
+pub const EXISTENTIAL_DEPOSIT: u64 = 1;
+
+/// The balance every account below 100 starts with
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+/// The experience types registered by `new_test_ext`
+pub const FRONTEND: pallet_popi::ExperienceTypeId = 0;
+pub const BACKEND: pallet_popi::ExperienceTypeId = 1;
+pub const MARKETING: pallet_popi::ExperienceTypeId = 2;
+pub const GRAPHIC_DESIGN: pallet_popi::ExperienceTypeId = 3;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (0..100).map(|who| (who, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_popi::GenesisConfig::<Test> {
+		experience_types: [&b"Frontend"[..], b"Backend", b"Marketing", b"GraphicDesign"]
+			.iter()
+			.map(|name| name.to_vec())
+			.collect(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Events are not deposited on the genesis block
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
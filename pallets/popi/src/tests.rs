@@ -1,23 +1,78 @@
-use crate::{mock::*, Error, Event, ExperienceType};
-use frame_support::{assert_noop, assert_ok};
-#[test]
-fn i_know_how_to_work_with_vectors() {}
+use crate::{
+	level_from_experience, migrations, mock::*, AccountsByLevel, Assignment, AwardWeighting,
+	BoardId, Bond, Bonds, Bounties, Bounty, ColumnIndex, ColumnTasks, Contribution, DisputeId,
+	DisputeStatus, DisputedInteractions, Disputes, EpochExperience, EpochReward, EpochRewards,
+	EpochStart, EpochSummary, EpochTotals, Error, Event, Evidence, EvidenceInfo,
+	ExperienceDecayedAt, ExperienceInfo, ExperienceStorage, ExperienceTypeId, ExponentialCurve,
+	FlatWeighting, HalvingDecay, Interaction, InteractionInfo, LevelBonusWeighting, LevelCurve,
+	LinearCurve, LinearDecay, LinearWeighting, NextExperienceTypeId, NoDecay, PairInteractions,
+	PendingEvidence, PolynomialCurve, RepetitionDecay, RewardsClaimed, TaskContributions, TaskId,
+	TaskStates, TaskStatus, UserExperience, Verdict, WeightInfo,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	traits::{
+		ConstU128, ConstU32, Currency, GenesisBuild, Hooks, OnRuntimeUpgrade, StorageVersion,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError, Percent,
+};
 
-#[test]
-fn correct_error_for_none_value() {
-	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(Popi::cause_error(RuntimeOrigin::signed(1)), Error::<Test>::NoneValue);
-	});
+/// A column anyone may pull tasks into, earning frontend experience
+fn column(name: &[u8]) -> (Vec<u8>, ExperienceTypeId, u32) {
+	(name.to_vec(), FRONTEND, 0)
+}
+
+/// Creates a board owned by `owner` with the workflow described in the README.
+/// Nobody has experience yet, so no column requires a minimum level.
+fn create_board(owner: u128) -> BoardId {
+	let board_id = crate::NextBoardId::<Test>::get();
+	assert_ok!(Popi::create_board(
+		RuntimeOrigin::signed(owner),
+		b"ecommerce".to_vec(),
+		vec![
+			(b"NEW".to_vec(), MARKETING, 0),
+			column(b"TODO"),
+			column(b"IN PROGRESS"),
+			column(b"CODE REVIEW"),
+			(b"QA".to_vec(), BACKEND, 0),
+			(b"DONE".to_vec(), MARKETING, 0),
+		],
+	));
+	board_id
+}
+
+/// Walks the "ready to be pulled" handshake and lets `approver` approve the work of `worker`,
+/// moving the task to `dst_state`
+fn approve(
+	approver: u128,
+	worker: u128,
+	board_id: BoardId,
+	task_id: TaskId,
+	dst_state: ColumnIndex,
+) -> DispatchResult {
+	Popi::mark_ready(RuntimeOrigin::signed(worker), board_id, task_id, vec![])?;
+	Popi::pull(RuntimeOrigin::signed(approver), board_id, task_id)?;
+	Popi::interact(RuntimeOrigin::signed(approver), worker, board_id, task_id, dst_state, vec![])
 }
 
+#[test]
+fn i_know_how_to_work_with_vectors() {}
+
 #[test]
 fn interactions_should_be_unique() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, 1, 1));
+		let board_id = create_board(3);
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+		// The task already left the first column, the same interaction can not happen twice
 		assert_noop!(
-			Popi::interact(RuntimeOrigin::signed(1), 2, 1, 1),
-			Error::<Test>::InteractionExisting
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::InvalidTransition
 		);
 	});
 }
@@ -26,7 +81,7 @@ fn interactions_should_be_unique() {
 fn create_user_experiences() {
 	new_test_ext().execute_with(|| {
 		let account_id = 42;
-		let exp_type = ExperienceType::Backend;
+		let exp_type = BACKEND;
 
 		// User exp has not been set yet, so it should return an error
 		assert!(Popi::get_user_experience(account_id, exp_type).is_err());
@@ -40,6 +95,1998 @@ fn create_user_experiences() {
 		// Successfully get the user experience
 		assert_ok!(Popi::get_user_experience(account_id, exp_type));
 		// Should not allow the creation of user experience for a different type that does not exist
-		assert!(Popi::get_user_experience(account_id, ExperienceType::Frontend).is_err());
+		assert!(Popi::get_user_experience(account_id, FRONTEND).is_err());
+	});
+}
+
+#[test]
+fn interact_credits_worker_experience() {
+	new_test_ext().execute_with(|| {
+		let worker = 2;
+		let board_id = create_board(3);
+
+		// The worker has no experience before the first interaction
+		assert!(Popi::get_user_experience(worker, FRONTEND).is_err());
+		assert_ok!(approve(1, worker, board_id, 1, 1));
+
+		let exp = Popi::get_user_experience(worker, FRONTEND).unwrap();
+		assert_eq!(exp.experience, 10);
+		assert_eq!(exp.level, 0);
+		assert_eq!(exp.experience_to_next_level, 90);
+		// The approver does not earn anything
+		assert!(Popi::get_user_experience(1, FRONTEND).is_err());
+	});
+}
+
+#[test]
+fn interactions_level_up_the_worker() {
+	new_test_ext().execute_with(|| {
+		let worker = 2;
+		let board_id = create_board(3);
+		// Every task is approved by someone else, so no approval loses value as a repetition
+		for task_id in 0..10 {
+			assert_ok!(approve(10 + task_id as u128, worker, board_id, task_id, 1));
+		}
+
+		let exp = Popi::get_user_experience(worker, FRONTEND).unwrap();
+		assert_eq!(exp.experience, 100);
+		assert_eq!(exp.level, 1);
+		// 100 * 2 ^ 1
+		assert_eq!(exp.experience_to_next_level, 200);
+	});
+}
+
+#[test]
+fn level_thresholds_follow_the_config() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Popi::calculate_exp_to_next_level(0), 100);
+		assert_eq!(Popi::calculate_exp_to_next_level(1), 200);
+		assert_eq!(Popi::calculate_exp_to_next_level(4), 1_600);
+		assert_eq!(Popi::calculate_level(0), (0, 100));
+		assert_eq!(Popi::calculate_level(350), (2, 350));
+		// MaxLevel is 20 in the mock, there is nothing left to earn after that
+		assert_eq!(Popi::calculate_exp_to_next_level(20), 0);
+		assert_eq!(Popi::calculate_level(u128::MAX), (20, 0));
+	});
+}
+
+#[test]
+fn level_curves_saturate_instead_of_overflowing() {
+	type Exponential = ExponentialCurve<ConstU128<100>, ConstU32<2>, ConstU32<10>>;
+	type Polynomial = PolynomialCurve<ConstU128<100>, ConstU32<2>>;
+	type Linear = LinearCurve<ConstU128<100>>;
+
+	assert_eq!(Exponential::experience_for_next_level(1), 102_400);
+	assert_eq!(Exponential::experience_for_next_level(13), u128::MAX);
+	assert_eq!(Polynomial::experience_for_next_level(2), 900);
+	assert_eq!(Polynomial::experience_for_next_level(u32::MAX), 100 * (1u128 << 64));
+	assert_eq!(Linear::experience_for_next_level(3), 400);
+
+	assert_eq!(level_from_experience::<Linear>(650, 100), (3, 350));
+	assert_eq!(level_from_experience::<Linear>(650, 2), (2, 0));
+	// Unreachable thresholds keep the user at the level they are, whatever the experience
+	assert_eq!(level_from_experience::<Exponential>(u128::MAX, 1_000).0, 13);
+}
+
+#[test]
+fn award_weightings_scale_with_the_approver_level() {
+	frame_support::parameter_types! {
+		pub const Floor: Percent = Percent::from_percent(20);
+		pub const Bonus: Percent = Percent::from_percent(50);
+	}
+	type Linear = LinearWeighting<Floor, ConstU32<4>>;
+	type Bonus50 = LevelBonusWeighting<Bonus>;
+
+	assert_eq!(FlatWeighting::weigh(10, 0), 10);
+	assert_eq!(FlatWeighting::weigh(10, 20), 10);
+	assert_eq!(Linear::weigh(100, 0), 20);
+	assert_eq!(Linear::weigh(100, 2), 60);
+	assert_eq!(Linear::weigh(100, 4), 100);
+	assert_eq!(Linear::weigh(100, 20), 100);
+	assert_eq!(Bonus50::weigh(10, 0), 10);
+	assert_eq!(Bonus50::weigh(10, 3), 25);
+	assert_eq!(Bonus50::weigh(u128::MAX, 3), u128::MAX);
+}
+
+#[test]
+fn approvals_from_experts_award_more_experience() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		// A fresh approver credits the base experience only
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 10);
+
+		// A level 3 frontend expert adds 10% of it per level
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 4, FRONTEND, 3));
+		assert_ok!(approve(4, 2, board_id, 1, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 23);
+		System::assert_has_event(
+			Event::ExperienceGained { who: 2, exp_type: FRONTEND, amount: 13 }.into(),
+		);
+		let awarded = Interaction::<Test>::iter_values().map(|metadata| metadata.awarded);
+		let mut awarded = awarded.collect::<Vec<_>>();
+		awarded.sort();
+		assert_eq!(awarded, vec![Some(10), Some(13)]);
+	});
+}
+
+#[test]
+fn repetition_decays_reduce_the_award() {
+	frame_support::parameter_types! {
+		pub const Step: Percent = Percent::from_percent(25);
+	}
+	type Linear = LinearDecay<Step>;
+
+	assert_eq!(NoDecay::decay(10, 5), 10);
+	assert_eq!((0..5).map(|r| HalvingDecay::decay(10, r)).collect::<Vec<_>>(), [10, 5, 2, 1, 0]);
+	assert_eq!(HalvingDecay::decay(u128::MAX, u32::MAX), 0);
+	assert_eq!((0..5).map(|r| Linear::decay(100, r)).collect::<Vec<_>>(), [100, 75, 50, 25, 0]);
+	assert_eq!(Linear::decay(100, u32::MAX), 0);
+}
+
+#[test]
+fn repeated_pairs_earn_less_within_the_window() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		// The same approver keeps approving the same worker on different tasks
+		for task_id in 0..3 {
+			assert_ok!(approve(1, 2, board_id, task_id, 1));
+		}
+		System::assert_has_event(
+			Event::InteractionRecorded {
+				approver: 1,
+				worker: 2,
+				board_id,
+				task_id: 2,
+				awarded: 2,
+				repetitions: 2,
+			}
+			.into(),
+		);
+		// 10 + 5 + 2
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 17);
+
+		// Another approver is not affected by the repetitions of the first one
+		assert_ok!(approve(4, 2, board_id, 3, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 27);
+
+		// PairWindow is 100 blocks in the mock, older approvals stop counting
+		System::set_block_number(102);
+		assert_ok!(approve(1, 2, board_id, 4, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 37);
+		assert_eq!(PairInteractions::<Test>::get(1, 2).into_inner(), vec![102]);
+	});
+}
+
+#[test]
+fn pairs_outside_the_window_are_pruned() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		System::set_block_number(50);
+		assert_ok!(approve(4, 2, board_id, 1, 1));
+
+		// Both pairs still count as repetitions
+		assert_eq!(Popi::sweep_pairs(1), 1);
+		assert!(crate::PairSweepCursor::<Test>::get().is_some());
+		assert_eq!(Popi::sweep_pairs(10), 1);
+		assert!(crate::PairSweepCursor::<Test>::get().is_none());
+		assert_eq!(PairInteractions::<Test>::iter().count(), 2);
+
+		// Only the approval of the first block is out of the window
+		System::set_block_number(101);
+		Popi::on_idle(101, Weight::MAX);
+		assert!(!PairInteractions::<Test>::contains_key(1, 2));
+		assert_eq!(PairInteractions::<Test>::get(4, 2).into_inner(), vec![50]);
+
+		System::set_block_number(150);
+		Popi::on_idle(150, Weight::MAX);
+		assert_eq!(PairInteractions::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn pair_sweeps_cover_every_pair_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		let blocks = BoundedVec::<u64, ConstU32<4>>::truncate_from(vec![1]);
+		for approver in 10..15 {
+			PairInteractions::<Test>::insert(approver, 2, &blocks);
+		}
+		System::set_block_number(200);
+
+		// Two pairs fit in each block, the sweep resumes after the pair it pruned last
+		let weight = <() as WeightInfo>::sweep_pairs(2);
+		for (block, left) in [(200, 3), (201, 1), (202, 0)] {
+			Popi::on_idle(block, weight);
+			assert_eq!(PairInteractions::<Test>::iter().count(), left);
+		}
+		// Every pair has been visited, the next sweep starts over
+		assert!(crate::PairSweepCursor::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn create_board_works() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(1);
+		assert_eq!(board_id, 0);
+		assert_eq!(crate::NextBoardId::<Test>::get(), 1);
+		System::assert_last_event(Event::BoardCreated { board_id, owner: 1 }.into());
+
+		let board = Popi::boards(board_id).unwrap();
+		assert_eq!(board.owner, 1);
+		assert_eq!(board.name.to_vec(), b"ecommerce".to_vec());
+		assert_eq!(board.columns.len(), 6);
+		assert_eq!(board.columns[2].name.to_vec(), b"IN PROGRESS".to_vec());
+		assert!(!board.archived);
+
+		// The next board gets the next id
+		assert_eq!(create_board(2), 1);
+	});
+}
+
+#[test]
+fn create_board_validates_its_input() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let columns = vec![column(b"TODO"), column(b"DONE")];
+		assert_noop!(
+			Popi::create_board(origin.clone(), vec![b'x'; 33], columns.clone()),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			Popi::create_board(origin.clone(), b"board".to_vec(), vec![column(b"TODO")]),
+			Error::<Test>::NotEnoughColumns
+		);
+		assert_noop!(
+			Popi::create_board(origin.clone(), b"board".to_vec(), vec![column(b"TODO"); 11]),
+			Error::<Test>::TooManyColumns
+		);
+		assert_noop!(
+			Popi::create_board(
+				origin,
+				b"board".to_vec(),
+				vec![column(b"TODO"), column(&[b'x'; 33])]
+			),
+			Error::<Test>::NameTooLong
+		);
+	});
+}
+
+#[test]
+fn only_the_owner_updates_and_archives_a_board() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(1);
+		let columns = vec![column(b"TODO"), column(b"DOING"), column(b"DONE")];
+
+		assert_noop!(
+			Popi::update_board(RuntimeOrigin::signed(2), board_id, b"b".to_vec(), columns.clone()),
+			Error::<Test>::NotBoardOwner
+		);
+		assert_noop!(
+			Popi::archive_board(RuntimeOrigin::signed(2), board_id),
+			Error::<Test>::NotBoardOwner
+		);
+		assert_noop!(
+			Popi::update_board(RuntimeOrigin::signed(1), 42, b"b".to_vec(), columns.clone()),
+			Error::<Test>::BoardNotFound
+		);
+
+		assert_ok!(Popi::update_board(
+			RuntimeOrigin::signed(1),
+			board_id,
+			b"kanban".to_vec(),
+			columns.clone()
+		));
+		System::assert_last_event(Event::BoardUpdated { board_id }.into());
+		let board = Popi::boards(board_id).unwrap();
+		assert_eq!(board.name.to_vec(), b"kanban".to_vec());
+		assert_eq!(board.columns.len(), 3);
+
+		assert_ok!(Popi::archive_board(RuntimeOrigin::signed(1), board_id));
+		System::assert_last_event(Event::BoardArchived { board_id }.into());
+		assert!(Popi::boards(board_id).unwrap().archived);
+
+		// An archived board is frozen
+		assert_noop!(
+			Popi::update_board(RuntimeOrigin::signed(1), board_id, b"b".to_vec(), columns),
+			Error::<Test>::BoardArchived
+		);
+		assert_noop!(
+			Popi::archive_board(RuntimeOrigin::signed(1), board_id),
+			Error::<Test>::BoardArchived
+		);
+	});
+}
+
+#[test]
+fn interactions_require_an_active_board() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, 0, 1, 1, vec![]),
+			Error::<Test>::BoardNotFound
+		);
+
+		let board_id = create_board(3);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+		assert_ok!(Popi::archive_board(RuntimeOrigin::signed(3), board_id));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::BoardArchived
+		);
+	});
+}
+
+#[test]
+fn tasks_move_one_column_forward() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		let task_id = 7;
+
+		// A new task is in the first column
+		assert_eq!(Popi::task_state(board_id, task_id), 0);
+		assert_ok!(approve(1, 2, board_id, task_id, 1));
+		assert_eq!(Popi::task_state(board_id, task_id), 1);
+		System::assert_last_event(
+			Event::TaskMoved { board_id, task_id, src_state: 0, dst_state: 1 }.into(),
+		);
+
+		// Columns can not be skipped, and tasks never move backwards
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(1), board_id, task_id, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(2), board_id, task_id));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(2), 1, board_id, task_id, 3, vec![]),
+			Error::<Test>::InvalidTransition
+		);
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(2), 1, board_id, task_id, 0, vec![]),
+			Error::<Test>::InvalidTransition
+		);
+
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(2), 1, board_id, task_id, 2, vec![]));
+		assert_eq!(Popi::task_state(board_id, task_id), 2);
+		// Other tasks are not affected
+		assert_eq!(Popi::task_state(board_id, task_id + 1), 0);
+	});
+}
+
+#[test]
+fn tasks_stop_at_the_last_column() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+
+		// The README board has 6 columns, from NEW (0) to DONE (5).
+		// Accounts 1 and 2 take turns working on the task and approving each other
+		for dst_state in 1..6 {
+			let (approver, worker) = if dst_state % 2 == 1 { (1, 2) } else { (2, 1) };
+			assert_ok!(approve(approver, worker, board_id, 1, dst_state));
+		}
+		assert_eq!(Popi::task_state(board_id, 1), 5);
+		// Nobody works on a completed task
+		assert_eq!(Popi::assignment(board_id, 1), None);
+		assert_noop!(
+			Popi::mark_ready(RuntimeOrigin::signed(1), board_id, 1, vec![]),
+			Error::<Test>::TaskCompleted
+		);
+	});
+}
+
+#[test]
+fn handshake_is_required_to_interact() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+
+		// Nobody declared the task ready yet
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::HandshakeMissing
+		);
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(1), board_id, 1),
+			Error::<Test>::TaskNotReady
+		);
+
+		// The first one declaring the task ready becomes its worker
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		System::assert_last_event(Event::TaskReady { board_id, task_id: 1, worker: 2 }.into());
+		assert_eq!(
+			Popi::assignment(board_id, 1),
+			Some(Assignment { worker: 2, status: TaskStatus::ReadyToBePulled })
+		);
+		assert_noop!(
+			Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]),
+			Error::<Test>::TaskAlreadyReady
+		);
+		assert_noop!(
+			Popi::mark_ready(RuntimeOrigin::signed(4), board_id, 1, vec![]),
+			Error::<Test>::NotAssignee
+		);
+
+		// Ready but not pulled yet
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::HandshakeMissing
+		);
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(2), board_id, 1),
+			Error::<Test>::CannotPullOwnTask
+		);
+
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+		System::assert_last_event(Event::TaskPulled { board_id, task_id: 1, by: 1 }.into());
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(4), board_id, 1),
+			Error::<Test>::TaskNotReady
+		);
+
+		// Only the one who pulled the task may approve it, and only for its worker
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(4), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::HandshakeMissing
+		);
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 4, board_id, 1, 1, vec![]),
+			Error::<Test>::NotAssignee
+		);
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]));
+
+		// The approver now works on the task in its new column
+		assert_eq!(
+			Popi::assignment(board_id, 1),
+			Some(Assignment { worker: 1, status: TaskStatus::InProgress })
+		);
+		assert_noop!(
+			Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]),
+			Error::<Test>::NotAssignee
+		);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(1), board_id, 1, vec![]));
+	});
+}
+
+#[test]
+fn pulled_tasks_can_be_given_back() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		assert_noop!(
+			Popi::unpull(RuntimeOrigin::signed(1), board_id, 1),
+			Error::<Test>::TaskNotPulled
+		);
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+
+		// Only the one who pulled the task gives it back
+		assert_noop!(Popi::unpull(RuntimeOrigin::signed(4), board_id, 1), Error::<Test>::NotPuller);
+		assert_ok!(Popi::unpull(RuntimeOrigin::signed(1), board_id, 1));
+		System::assert_last_event(Event::TaskReleased { board_id, task_id: 1, puller: 1 }.into());
+		assert_eq!(
+			Popi::assignment(board_id, 1),
+			Some(Assignment { worker: 2, status: TaskStatus::ReadyToBePulled })
+		);
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::HandshakeMissing
+		);
+
+		// Someone else may pull and approve it now
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(4), board_id, 1));
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(4), 2, board_id, 1, 1, vec![]));
+	});
+}
+
+#[test]
+fn board_owners_release_pulled_tasks() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_noop!(
+			Popi::release_task(RuntimeOrigin::signed(3), board_id, 1),
+			Error::<Test>::TaskNotAssigned
+		);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+
+		// The puller went away without approving the task, only the owner takes it back
+		assert_noop!(
+			Popi::release_task(RuntimeOrigin::signed(4), board_id, 1),
+			Error::<Test>::NotBoardOwner
+		);
+		assert_ok!(Popi::release_task(RuntimeOrigin::signed(3), board_id, 1));
+		System::assert_last_event(Event::TaskReleased { board_id, task_id: 1, puller: 1 }.into());
+		assert_eq!(
+			Popi::assignment(board_id, 1),
+			Some(Assignment { worker: 2, status: TaskStatus::ReadyToBePulled })
+		);
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::HandshakeMissing
+		);
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(4), board_id, 1));
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(4), 2, board_id, 1, 1, vec![]));
+	});
+}
+
+#[test]
+fn board_owners_evict_squatting_workers() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		// 2 claims the task without ever getting it done
+		let commit = Evidence::GitCommit(vec![0xab; 20].try_into().unwrap());
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![commit]));
+
+		// Only the owner takes it back
+		assert_noop!(
+			Popi::release_task(RuntimeOrigin::signed(4), board_id, 1),
+			Error::<Test>::NotBoardOwner
+		);
+		assert_noop!(
+			Popi::release_task(RuntimeOrigin::signed(2), board_id, 1),
+			Error::<Test>::NotBoardOwner
+		);
+		assert_ok!(Popi::release_task(RuntimeOrigin::signed(3), board_id, 1));
+		System::assert_last_event(Event::WorkerEvicted { board_id, task_id: 1, worker: 2 }.into());
+		assert_eq!(Popi::assignment(board_id, 1), None);
+		assert!(PendingEvidence::<Test>::get(board_id, 1).is_none());
+
+		// Anyone may claim it now
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(4), board_id, 1, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 4, board_id, 1, 1, vec![]));
+
+		// So does an approver who never works on the task in its new column
+		assert_ok!(Popi::release_task(RuntimeOrigin::signed(3), board_id, 1));
+		System::assert_last_event(Event::WorkerEvicted { board_id, task_id: 1, worker: 1 }.into());
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		assert_eq!(
+			Popi::assignment(board_id, 1),
+			Some(Assignment { worker: 2, status: TaskStatus::ReadyToBePulled })
+		);
+	});
+}
+
+#[test]
+fn self_interactions_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(2), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::SelfInteraction
+		);
+		// Not even with a board that does not exist
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(2), 2, 42, 1, 1, vec![]),
+			Error::<Test>::SelfInteraction
+		);
+		assert!(Popi::get_user_experience(2, FRONTEND).is_err());
+	});
+}
+
+#[test]
+fn column_worker_can_not_approve_the_column() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+
+		// Account 2 worked on the task in this column, crediting someone else for that work
+		// does not make the move legit
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(2), 3, board_id, 1, 1, vec![]),
+			Error::<Test>::ApproverWorkedOnColumn
+		);
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(2), board_id, 1),
+			Error::<Test>::CannotPullOwnTask
+		);
+		assert!(Popi::get_user_experience(3, FRONTEND).is_err());
+
+		// Someone else may
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]));
+	});
+}
+
+#[test]
+fn only_experts_pull_tasks_into_a_column() {
+	new_test_ext().execute_with(|| {
+		let expert_column = (b"CODE REVIEW".to_vec(), BACKEND, 2);
+		assert_ok!(Popi::create_board(
+			RuntimeOrigin::signed(3),
+			b"api".to_vec(),
+			vec![column(b"IN PROGRESS"), expert_column],
+		));
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), 0, 1, vec![]));
+
+		// Account 1 has no backend experience at all
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::ApproverLevelTooLow
+		);
+
+		// Level 1 is not enough either
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 1, BACKEND, 1));
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::ApproverLevelTooLow
+		);
+
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 1, BACKEND, 2));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, 0, 1, 1, vec![]));
+
+		// The worker earns the experience of the column role, weighted by the approver level
+		assert_eq!(Popi::get_user_experience(2, BACKEND).unwrap().experience, 12);
+		assert!(Popi::get_user_experience(2, FRONTEND).is_err());
+	});
+}
+
+#[test]
+fn boards_keep_the_columns_tasks_are_in() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		// Task 1 is in IN PROGRESS, task 2 in TODO
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+		assert_ok!(approve(2, 1, board_id, 1, 2));
+		assert_ok!(approve(1, 2, board_id, 2, 1));
+		assert_eq!(ColumnTasks::<Test>::get(board_id, 1), 1);
+		assert_eq!(ColumnTasks::<Test>::get(board_id, 2), 1);
+
+		// Task 1 would be stranded
+		let columns = vec![column(b"NEW"), column(b"TODO"), column(b"DONE")];
+		assert_noop!(
+			Popi::update_board(RuntimeOrigin::signed(3), board_id, b"b".to_vec(), columns),
+			Error::<Test>::ColumnHasTasks
+		);
+		// Task 2 would be completed without its bounty being paid
+		let columns = vec![column(b"NEW"), column(b"TODO")];
+		assert_noop!(
+			Popi::update_board(RuntimeOrigin::signed(3), board_id, b"b".to_vec(), columns),
+			Error::<Test>::ColumnHasTasks
+		);
+
+		let columns =
+			vec![column(b"NEW"), column(b"TODO"), column(b"IN PROGRESS"), column(b"DONE")];
+		assert_ok!(Popi::update_board(RuntimeOrigin::signed(3), board_id, b"b".to_vec(), columns));
+		assert_eq!(Popi::boards(board_id).unwrap().columns.len(), 4);
+
+		// A completed task does not hold the last column
+		assert_ok!(approve(1, 2, board_id, 1, 3));
+		assert_eq!(ColumnTasks::<Test>::get(board_id, 2), 0);
+		assert_eq!(ColumnTasks::<Test>::get(board_id, 3), 1);
+		let columns = vec![column(b"NEW"), column(b"TODO"), column(b"DONE")];
+		assert_ok!(Popi::update_board(RuntimeOrigin::signed(3), board_id, b"b".to_vec(), columns));
+		assert_ok!(approve(2, 1, board_id, 2, 2));
+	});
+}
+
+#[test]
+fn interact_checks_the_approver_level() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 1));
+
+		// The board owner raises the bar of the TODO column after the task has been pulled
+		let mut columns = vec![column(b"NEW"), column(b"TODO")];
+		columns[1].2 = 1;
+		assert_ok!(Popi::update_board(RuntimeOrigin::signed(3), board_id, b"b".to_vec(), columns));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::ApproverLevelTooLow
+		);
+	});
+}
+
+#[test]
+fn seed_expert_works() {
+	new_test_ext().execute_with(|| {
+		// Only the admin origin may seed experts
+		assert_noop!(
+			Popi::seed_expert(RuntimeOrigin::signed(1), 1, FRONTEND, 2),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Popi::seed_expert(RuntimeOrigin::root(), 1, 4, 2),
+			Error::<Test>::ExperienceTypeNotFound
+		);
+
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 1, FRONTEND, 2));
+		System::assert_last_event(
+			Event::ExpertSeeded { who: 1, exp_type: FRONTEND, level: 2 }.into(),
+		);
+		let exp = Popi::get_user_experience(1, FRONTEND).unwrap();
+		// 100 + 200
+		assert_eq!(exp.experience, 300);
+		assert_eq!(exp.level, 2);
+		assert_eq!(exp.experience_to_next_level, 400);
+
+		// Seeding a lower level does not take experience away
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 1, FRONTEND, 1));
+		assert_eq!(Popi::get_user_experience(1, FRONTEND).unwrap().level, 2);
+	});
+}
+
+#[test]
+fn board_owners_can_not_raise_their_own_level() {
+	new_test_ext().execute_with(|| {
+		// A fresh account creating a board of its own gains no say over the levels
+		let board_id = create_board(7);
+		for exp_type in [FRONTEND, BACKEND, MARKETING] {
+			assert_noop!(
+				Popi::seed_expert(RuntimeOrigin::signed(7), 7, exp_type, 5),
+				DispatchError::BadOrigin
+			);
+		}
+		assert!(Popi::get_user_experience(7, FRONTEND).is_err());
+		assert_eq!(AccountsByLevel::<Test>::iter_prefix((FRONTEND,)).count(), 0);
+
+		// So it can not approve work on the boards of others requiring experts
+		let expert_column = (b"CODE REVIEW".to_vec(), FRONTEND, 2);
+		assert_ok!(Popi::create_board(
+			RuntimeOrigin::signed(3),
+			b"api".to_vec(),
+			vec![column(b"IN PROGRESS"), expert_column],
+		));
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id + 1, 0, vec![]));
+		assert_noop!(
+			Popi::pull(RuntimeOrigin::signed(7), board_id + 1, 0),
+			Error::<Test>::ApproverLevelTooLow
+		);
+	});
+}
+
+#[test]
+fn experience_types_are_managed_by_the_admin_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Popi::register_experience_type(RuntimeOrigin::signed(1), b"DevOps".to_vec()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Popi::register_experience_type(RuntimeOrigin::root(), vec![0; 33]),
+			Error::<Test>::NameTooLong
+		);
+
+		assert_ok!(Popi::register_experience_type(RuntimeOrigin::root(), b"DevOps".to_vec()));
+		System::assert_last_event(
+			Event::ExperienceTypeRegistered { exp_type: 4, name: b"DevOps".to_vec() }.into(),
+		);
+		assert_eq!(NextExperienceTypeId::<Test>::get(), 5);
+		let info = Popi::experience_types(4).unwrap();
+		assert_eq!(info.name.to_vec(), b"DevOps".to_vec());
+		assert!(info.active);
+
+		assert_noop!(
+			Popi::update_experience_type(RuntimeOrigin::signed(1), 4, b"Ops".to_vec(), false),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Popi::update_experience_type(RuntimeOrigin::root(), 5, b"Ops".to_vec(), false),
+			Error::<Test>::ExperienceTypeNotFound
+		);
+		assert_ok!(Popi::update_experience_type(RuntimeOrigin::root(), 4, b"Ops".to_vec(), false));
+		System::assert_last_event(
+			Event::ExperienceTypeUpdated { exp_type: 4, active: false }.into(),
+		);
+		let info = Popi::experience_types(4).unwrap();
+		assert_eq!(info.name.to_vec(), b"Ops".to_vec());
+		assert!(!info.active);
+	});
+}
+
+#[test]
+fn only_active_experience_types_can_be_used() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(1);
+		assert_noop!(
+			Popi::create_board(
+				RuntimeOrigin::signed(1),
+				b"Popi".to_vec(),
+				vec![column(b"TODO"), (b"DONE".to_vec(), 4, 0)],
+			),
+			Error::<Test>::ExperienceTypeNotFound
+		);
+
+		assert_ok!(Popi::update_experience_type(
+			RuntimeOrigin::root(),
+			BACKEND,
+			b"Backend".to_vec(),
+			false
+		));
+		assert_noop!(
+			Popi::create_board(
+				RuntimeOrigin::signed(1),
+				b"Popi".to_vec(),
+				vec![column(b"TODO"), (b"DONE".to_vec(), BACKEND, 0)],
+			),
+			Error::<Test>::ExperienceTypeInactive
+		);
+		assert_noop!(
+			Popi::seed_expert(RuntimeOrigin::root(), 2, BACKEND, 1),
+			Error::<Test>::ExperienceTypeInactive
+		);
+
+		// Existing columns requiring the inactive type keep working, QA requires Backend
+		for (worker, approver, dst_state) in [(1, 2, 1), (2, 3, 2), (3, 4, 3), (4, 5, 4)] {
+			assert_ok!(approve(approver, worker, board_id, 0, dst_state));
+		}
+		assert_eq!(Popi::get_user_experience(4, BACKEND).unwrap().experience, 10);
+	});
+}
+
+#[test]
+fn migration_to_v1_upgrades_the_storage_of_the_first_runtime() {
+	new_test_ext().execute_with(|| {
+		use migrations::v0;
+
+		// The storage of the first runtime, before the pallet accounts existed
+		StorageVersion::new(0).put::<Popi>();
+		Balances::make_free_balance_be(&Popi::bounty_account(), 0);
+		Balances::make_free_balance_be(&Popi::reward_account(), 0);
+		v0::Something::<Test>::put(7);
+		let experience = UserExperience::<Test> {
+			account_id: 2,
+			experience: 150,
+			level: 0,
+			experience_to_next_level: 0,
+		};
+		v0::ExperienceStorage::<Test>::insert((2, v0::ExperienceType::Backend), experience);
+		let legacy =
+			v0::InteractionIdentifier::<Test> { approver: 1, worker: 2, board_id: 7, task_id: 0 };
+		v0::Interaction::<Test>::insert(legacy, ());
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Popi>(), 1);
+		assert!(!v0::Something::<Test>::exists());
+		assert_eq!(NextExperienceTypeId::<Test>::get(), 4);
+		assert_eq!(
+			Popi::experience_types(GRAPHIC_DESIGN).unwrap().name.to_vec(),
+			b"GraphicDesign".to_vec()
+		);
+		// The experience is re-keyed, its level recomputed and indexed
+		let exp = Popi::get_user_experience(2, BACKEND).unwrap();
+		assert_eq!((exp.experience, exp.level, exp.experience_to_next_level), (150, 1, 150));
+		assert_eq!(ExperienceStorage::<Test>::iter().count(), 1);
+		assert_eq!(Popi::accounts_by_level(BACKEND, 0, None, 10).accounts, vec![(2, 1)]);
+		// The interaction is kept with both columns set to 0, and indexed
+		let upi = crate::InteractionIdentifier::<Test> {
+			approver: 1,
+			worker: 2,
+			board_id: 7,
+			task_id: 0,
+			src_state: 0,
+			dst_state: 0,
+		};
+		assert!(!v0::Interaction::<Test>::contains_key(legacy));
+		let metadata = Interaction::<Test>::get(upi).unwrap();
+		assert_eq!((metadata.src_state, metadata.dst_state, metadata.bond), (0, 0, 0));
+		assert_eq!((metadata.block, metadata.exp_type, metadata.awarded), (None, None, None));
+		assert_eq!(Popi::interactions_by_board(7, None, 10).interactions.len(), 1);
+		assert_eq!(Popi::interactions_by_worker(2, None, 10).interactions.len(), 1);
+		// What it credited is unknown, so it can not be revoked
+		assert_noop!(
+			Popi::challenge_interaction(RuntimeOrigin::signed(3), 1, 2, 7, 0, 0, 0),
+			Error::<Test>::MigratedInteraction
+		);
+		assert_eq!(Balances::free_balance(Popi::bounty_account()), EXISTENTIAL_DEPOSIT);
+		assert_eq!(Balances::free_balance(Popi::reward_account()), EXISTENTIAL_DEPOSIT);
+
+		// Running it again does nothing
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Popi::get_user_experience(2, BACKEND).unwrap().experience, 150);
+		assert_eq!(Interaction::<Test>::iter().count(), 1);
+	});
+}
+
+#[test]
+fn runtime_api_queries_work() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_eq!(Popi::user_experience(2, FRONTEND), None);
+		assert_eq!(Popi::user_profile(2), vec![]);
+		assert!(!Popi::interaction_exists(1, 2, board_id, 0, 0, 1));
+
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 2, BACKEND, 1));
+
+		let frontend = ExperienceInfo { experience: 10, level: 0, experience_to_next_level: 90 };
+		let backend = ExperienceInfo { experience: 100, level: 1, experience_to_next_level: 200 };
+		assert_eq!(Popi::user_experience(2, FRONTEND), Some(frontend.clone()));
+		let mut profile = Popi::user_profile(2);
+		profile.sort_by_key(|(exp_type, _)| *exp_type);
+		assert_eq!(profile, vec![(FRONTEND, frontend), (BACKEND, backend)]);
+		assert!(Popi::interaction_exists(1, 2, board_id, 0, 0, 1));
+		assert!(!Popi::interaction_exists(2, 1, board_id, 0, 0, 1));
+
+		let thresholds = Popi::level_thresholds();
+		assert_eq!(thresholds.len(), 20);
+		assert_eq!(thresholds[..3], [100, 200, 400]);
+	});
+}
+
+#[test]
+fn experience_changes_emit_events() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		System::assert_has_event(
+			Event::InteractionRecorded {
+				approver: 1,
+				worker: 2,
+				board_id,
+				task_id: 0,
+				awarded: 10,
+				repetitions: 0,
+			}
+			.into(),
+		);
+		System::assert_has_event(Event::ExperienceCreated { who: 2, exp_type: FRONTEND }.into());
+		System::assert_has_event(
+			Event::ExperienceGained { who: 2, exp_type: FRONTEND, amount: 10 }.into(),
+		);
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(record.event, RuntimeEvent::Popi(Event::LeveledUp { .. }))));
+
+		// 10 + 290 experience reaches level 2 at once
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 2, FRONTEND, 2));
+		System::assert_has_event(
+			Event::ExperienceGained { who: 2, exp_type: FRONTEND, amount: 290 }.into(),
+		);
+		System::assert_has_event(
+			Event::LeveledUp { who: 2, exp_type: FRONTEND, new_level: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn genesis_builds_boards_and_experts() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		experience_types: vec![b"Product".to_vec(), b"Frontend".to_vec()],
+		boards: vec![(1, b"ecommerce".to_vec(), vec![(b"TODO".to_vec(), 0, 1), column(b"DONE")])],
+		experts: vec![(1, 0, 3), (2, 1, 1)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::from(t).execute_with(|| {
+		assert_eq!(NextExperienceTypeId::<Test>::get(), 2);
+		assert_eq!(Popi::experience_types(1).unwrap().name.to_vec(), b"Frontend".to_vec());
+		let board = Popi::boards(0).unwrap();
+		assert_eq!(board.owner, 1);
+		assert_eq!(board.columns[0].min_level, 1);
+		assert_eq!(Popi::get_user_experience(1, 0).unwrap().level, 3);
+		assert_eq!(Popi::get_user_experience(2, 1).unwrap().level, 1);
+	});
+}
+
+#[test]
+fn interactions_are_kept_in_a_bounded_history() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		// MaxPositiveUserInteractions is 3 in the mock
+		for task_id in 0..4 {
+			System::set_block_number(10 + task_id as u64);
+			Timestamp::set_timestamp(1_000 * task_id as u64);
+			assert_ok!(approve(1, 2, board_id, task_id, 1));
+		}
+
+		let history = Popi::worker_history(2);
+		assert_eq!(history.len(), 3);
+		// The oldest interaction has been evicted
+		assert_eq!(history.iter().map(|entry| entry.task_id).collect::<Vec<_>>(), vec![1, 2, 3]);
+		let last = history.last().unwrap();
+		assert_eq!(last.counterpart, 1);
+		assert_eq!((last.board_id, last.dst_state), (board_id, 1));
+		assert_eq!((last.block, last.moment), (13, 3_000));
+
+		let history = Popi::approver_history(1);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history[0].counterpart, 2);
+		assert_eq!(history[0].task_id, 1);
+		assert!(Popi::worker_history(1).is_empty());
+	});
+}
+
+#[test]
+fn interactions_are_indexed_by_board_and_worker() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		let other_board = create_board(3);
+		for task_id in 0..3 {
+			assert_ok!(approve(1, 2, board_id, task_id, 1));
+		}
+		assert_ok!(approve(2, 4, other_board, 0, 1));
+
+		let first = Popi::interactions_by_board(board_id, None, 2);
+		assert_eq!(first.interactions.len(), 2);
+		let second = Popi::interactions_by_board(board_id, first.next_cursor, 2);
+		assert_eq!(second.interactions.len(), 1);
+		assert_eq!(second.next_cursor, None);
+		let mut tasks = first
+			.interactions
+			.iter()
+			.chain(second.interactions.iter())
+			.map(|interaction| interaction.task_id)
+			.collect::<Vec<_>>();
+		tasks.sort();
+		assert_eq!(tasks, vec![0, 1, 2]);
+
+		let page = Popi::interactions_by_worker(4, None, 100);
+		assert_eq!(
+			page.interactions,
+			vec![InteractionInfo {
+				approver: 2,
+				worker: 4,
+				board_id: other_board,
+				task_id: 0,
+				src_state: 0,
+				dst_state: 1
+			}]
+		);
+		assert_eq!(page.next_cursor, None);
+		assert_eq!(Popi::interactions_by_worker(2, None, 100).interactions.len(), 3);
+		assert!(Popi::interactions_by_worker(1, None, 100).interactions.is_empty());
+	});
+}
+
+#[test]
+fn interactions_store_their_metadata() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		System::set_block_number(7);
+		Timestamp::set_timestamp(42_000);
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+
+		let (_, metadata) = Interaction::<Test>::iter().next().unwrap();
+		assert_eq!(metadata.block, Some(7));
+		assert_eq!(metadata.moment, Some(42_000));
+		assert_eq!((metadata.src_state, metadata.dst_state), (0, 1));
+		assert_eq!(metadata.exp_type, Some(FRONTEND));
+		assert_eq!(metadata.awarded, Some(10));
+		assert_eq!(metadata.evidence, None);
+	});
+}
+
+#[test]
+fn interactions_keep_the_evidence_of_the_work() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		let commit = Evidence::GitCommit(vec![0xab; 20].try_into().unwrap());
+		let pull_request = Evidence::PullRequest([7; 32]);
+		let review = Evidence::IpfsCid(b"bafy".to_vec().try_into().unwrap());
+		assert_noop!(
+			Popi::mark_ready(
+				RuntimeOrigin::signed(2),
+				board_id,
+				0,
+				vec![commit.clone(), pull_request.clone(), review.clone()]
+			),
+			Error::<Test>::TooMuchEvidence
+		);
+
+		assert_ok!(Popi::mark_ready(
+			RuntimeOrigin::signed(2),
+			board_id,
+			0,
+			vec![commit.clone(), pull_request.clone()]
+		));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 0));
+		assert_ok!(Popi::interact(
+			RuntimeOrigin::signed(1),
+			2,
+			board_id,
+			0,
+			1,
+			vec![review.clone()]
+		));
+
+		assert!(PendingEvidence::<Test>::get(board_id, 0).is_none());
+		assert_eq!(
+			Popi::interaction_evidence(1, 2, board_id, 0, 0, 1),
+			Some(EvidenceInfo { worker: vec![commit, pull_request], approver: vec![review] })
+		);
+		let (_, metadata) = Interaction::<Test>::iter().next().unwrap();
+		assert!(metadata.evidence.is_some());
+
+		// Without evidence nothing is stored besides the interaction
+		assert_ok!(approve(2, 3, board_id, 0, 2));
+		assert_eq!(Popi::interaction_evidence(2, 3, board_id, 0, 1, 2), None);
+	});
+}
+
+#[test]
+fn experience_decays_while_inactive() {
+	new_test_ext().execute_with(|| {
+		ExperienceHalfLife::set(&Some(100));
+		let board_id = create_board(3);
+		// 100 + 200 experience, level 2
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 2, FRONTEND, 2));
+		assert_eq!(ExperienceDecayedAt::<Test>::get((2, FRONTEND)), Some((1, 300)));
+
+		// Reads see the decay right away, without writing it
+		System::set_block_number(101);
+		let experience = Popi::get_user_experience(2, FRONTEND).unwrap();
+		assert_eq!((experience.experience, experience.level), (150, 1));
+		assert_eq!(experience.experience_to_next_level, 150);
+		assert_eq!(ExperienceStorage::<Test>::get((2, FRONTEND)).unwrap().experience, 300);
+
+		// The idle sweep stores it
+		Popi::on_idle(101, Weight::MAX);
+		let experience = ExperienceStorage::<Test>::get((2, FRONTEND)).unwrap();
+		assert_eq!((experience.experience, experience.level), (150, 1));
+		System::assert_last_event(
+			Event::ExperienceDecayed { who: 2, exp_type: FRONTEND, lost: 150, level: 1 }.into(),
+		);
+		// The decay is still counted from the last gain
+		assert_eq!(ExperienceDecayedAt::<Test>::get((2, FRONTEND)), Some((1, 300)));
+
+		// Half a half-life later a quarter of it is lost. The sweep does not store it as long
+		// as it does not cost a level
+		System::set_block_number(151);
+		Popi::on_idle(151, Weight::MAX);
+		assert_eq!(ExperienceStorage::<Test>::get((2, FRONTEND)).unwrap().experience, 150);
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 113);
+		// It is lost before the new experience counts, which restarts the decay
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		// 150 - 37 + 10
+		assert_eq!(ExperienceStorage::<Test>::get((2, FRONTEND)).unwrap().experience, 123);
+		assert_eq!(ExperienceDecayedAt::<Test>::get((2, FRONTEND)), Some((151, 123)));
+	});
+}
+
+#[test]
+fn experience_decays_the_same_however_often_it_is_settled() {
+	new_test_ext().execute_with(|| {
+		ExperienceHalfLife::set(&Some(100));
+		// 10 is settled every 10 blocks, 11 never
+		for who in [10, 11] {
+			assert_ok!(Popi::add_user_experience(who, FRONTEND, 1_000));
+		}
+		for block in (11..=251).step_by(10) {
+			System::set_block_number(block);
+			Popi::settle_decay(&10, FRONTEND, true);
+		}
+		// Two and a half half-lives later: 1000 / 4 - 250 / 4
+		let decayed = |who| Popi::get_user_experience(who, FRONTEND).unwrap().experience;
+		assert_eq!(decayed(10), 188);
+		assert_eq!(decayed(11), 188);
+
+		// A revert takes its share out of the experience the decay is counted from
+		assert_ok!(Popi::revert_experience(&10, FRONTEND, 94));
+		assert_eq!(decayed(10), 94);
+		System::set_block_number(301);
+		assert_eq!(decayed(10), 62);
+		assert_eq!(decayed(11), 125);
+	});
+}
+
+#[test]
+fn decay_sweeps_resume_where_they_stopped() {
+	new_test_ext().execute_with(|| {
+		ExperienceHalfLife::set(&Some(100));
+		for who in 10..13 {
+			assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), who, FRONTEND, 1));
+		}
+		System::set_block_number(101);
+
+		assert_eq!(Popi::sweep_decay(2), 2);
+		assert!(crate::DecaySweepCursor::<Test>::get().is_some());
+		let decayed =
+			|who: u128| ExperienceStorage::<Test>::get((who, FRONTEND)).unwrap().experience;
+		assert_eq!((10..13).filter(|who| decayed(*who) == 50).count(), 2);
+
+		// The last one is settled by the next sweep, which then starts over
+		assert_eq!(Popi::sweep_decay(2), 1);
+		assert!(crate::DecaySweepCursor::<Test>::get().is_none());
+		assert!((10..13).all(|who| decayed(who) == 50));
+
+		// Without a half-life nothing decays nor is swept
+		ExperienceHalfLife::set(&None);
+		System::set_block_number(1_000);
+		Popi::on_idle(1_000, Weight::MAX);
+		assert_eq!(ExperienceStorage::<Test>::get((10, FRONTEND)).unwrap().experience, 50);
+		assert!(crate::DecaySweepCursor::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn accounts_are_indexed_by_level() {
+	new_test_ext().execute_with(|| {
+		ExperienceHalfLife::set(&Some(100));
+		for (who, level) in [(10, 0), (11, 1), (12, 3), (13, 2), (14, 4)] {
+			assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), who, FRONTEND, level));
+		}
+
+		// By ascending level
+		let page = Popi::accounts_by_level(FRONTEND, 1, None, 10);
+		assert_eq!(page.accounts, vec![(11, 1), (13, 2), (12, 3), (14, 4)]);
+		assert_eq!(page.next_cursor, None);
+		let first = Popi::accounts_by_level(FRONTEND, 0, None, 3);
+		assert_eq!(first.accounts, vec![(10, 0), (11, 1), (13, 2)]);
+		let second = Popi::accounts_by_level(FRONTEND, 0, first.next_cursor, 3);
+		assert_eq!(second.accounts, vec![(12, 3), (14, 4)]);
+		assert_eq!(second.next_cursor, None);
+		assert!(Popi::accounts_by_level(BACKEND, 0, None, 10).accounts.is_empty());
+
+		// Leveling up moves the account to its new level, within which the accounts are
+		// ordered by the hash of their id
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 10, FRONTEND, 3));
+		let mut page = Popi::accounts_by_level(FRONTEND, 3, None, 10).accounts;
+		assert_eq!(page.pop(), Some((14, 4)));
+		page.sort();
+		assert_eq!(page, vec![(10, 3), (12, 3)]);
+		assert_eq!(AccountsByLevel::<Test>::iter_prefix((FRONTEND,)).count(), 5);
+
+		// Accounts whose experience decayed below the level are left out
+		System::set_block_number(101);
+		assert!(Popi::accounts_by_level(FRONTEND, 4, None, 10).accounts.is_empty());
+	});
+}
+
+/// Makes 20, 21 and 22 frontend experts able to judge the frontend interactions
+fn seed_jurors() {
+	for juror in [20, 21, 22] {
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), juror, FRONTEND, 2));
+	}
+}
+
+/// 3 challenges the approval of the work of 2 by 1 on `task_id`, moving it to the second column
+fn challenge(board_id: BoardId, task_id: TaskId) -> DispatchResult {
+	Popi::challenge_interaction(RuntimeOrigin::signed(3), 1, 2, board_id, task_id, 0, 1)
+}
+
+/// Draws the jury of `dispute_id`, in a block after the challenge
+fn draw(dispute_id: DisputeId) -> DispatchResultWithPostInfo {
+	Popi::draw_jury(RuntimeOrigin::signed(4), dispute_id, 10)
+}
+
+#[test]
+fn guilty_approvers_are_slashed_and_the_award_reverted() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		seed_jurors();
+		// The challenger is an expert too, but may not judge their own challenge
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 3, FRONTEND, 2));
+		assert_ok!(Popi::add_user_experience(2, FRONTEND, 95));
+		// The approval locks a bond of 100
+		BondPerExperience::set(&10);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 150));
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().level, 1);
+		assert_noop!(Popi::unbond(RuntimeOrigin::signed(1), 100), Error::<Test>::BondLocked);
+		assert_ok!(Popi::unbond(RuntimeOrigin::signed(1), 50));
+
+		assert_ok!(challenge(board_id, 0));
+		assert_eq!(Balances::reserved_balance(3), 50);
+		System::set_block_number(2);
+		assert_ok!(draw(0));
+		let mut jury = Disputes::<Test>::get(0).unwrap().jury.into_inner();
+		jury.sort();
+		assert_eq!(jury, vec![20, 21, 22]);
+
+		// The dispute is closed by the last vote, two out of three find the approver guilty
+		assert_ok!(Popi::vote(RuntimeOrigin::signed(20), 0, true));
+		assert_ok!(Popi::vote(RuntimeOrigin::signed(21), 0, true));
+		assert!(Disputes::<Test>::contains_key(0));
+		assert_ok!(Popi::vote(RuntimeOrigin::signed(22), 0, false));
+		assert!(!Disputes::<Test>::contains_key(0));
+
+		let experience = Popi::get_user_experience(2, FRONTEND).unwrap();
+		assert_eq!((experience.experience, experience.level), (95, 0));
+		assert_eq!(AccountsByLevel::<Test>::iter_prefix((FRONTEND,)).count(), 5);
+		// Only the bond the approval locked is slashed
+		assert_eq!(
+			Bonds::<Test>::get(1),
+			Bond { active: 0, unbonding: 50, unlock_at: 21, locks: Default::default() }
+		);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 150);
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+		System::assert_has_event(
+			Event::ExperienceReverted { who: 2, exp_type: FRONTEND, amount: 10, level: 0 }.into(),
+		);
+		System::assert_has_event(Event::ApproverSlashed { who: 1, amount: 100 }.into());
+		System::assert_last_event(
+			Event::DisputeResolved { dispute_id: 0, verdict: Verdict::Guilty }.into(),
+		);
+		// Nothing has been earned in the epoch anymore
+		assert!(!EpochExperience::<Test>::contains_key(0, 2));
+		assert_eq!(EpochTotals::<Test>::get(0), EpochSummary::default());
+
+		// The interaction stays revoked
+		let upi = Interaction::<Test>::iter_keys().next().unwrap();
+		assert_eq!(
+			DisputedInteractions::<Test>::get(upi),
+			Some(DisputeStatus::Closed(Verdict::Guilty))
+		);
+		assert_noop!(challenge(board_id, 0), Error::<Test>::AlreadyChallenged);
+	});
+}
+
+#[test]
+fn challenges_are_judged_by_experts_of_the_experience_credited() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		seed_jurors();
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		// The column credited frontend experience, it credits backend experience from now on
+		let columns =
+			vec![(b"NEW".to_vec(), MARKETING, 0), (b"TODO".to_vec(), BACKEND, 0), column(b"DONE")];
+		assert_ok!(Popi::update_board(RuntimeOrigin::signed(4), board_id, b"b".to_vec(), columns));
+
+		assert_ok!(challenge(board_id, 0));
+		System::set_block_number(2);
+		assert_ok!(draw(0));
+		let dispute = Disputes::<Test>::get(0).unwrap();
+		assert_eq!(dispute.exp_type, FRONTEND);
+		let mut jury = dispute.jury.into_inner();
+		jury.sort();
+		assert_eq!(jury, vec![20, 21, 22]);
+
+		// So the frontend experience is the one taken back
+		for juror in [20, 21, 22] {
+			assert_ok!(Popi::vote(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 0);
+		assert!(Popi::get_user_experience(2, BACKEND).is_err());
+	});
+}
+
+#[test]
+fn approvals_can_only_be_challenged_within_the_challenge_period() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		seed_jurors();
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+
+		// ChallengePeriod is 30 blocks in the mock
+		System::set_block_number(31);
+		assert_ok!(challenge(board_id, 0));
+		System::set_block_number(32);
+		assert_noop!(challenge(board_id, 1), Error::<Test>::ChallengePeriodOver);
+	});
+}
+
+#[test]
+fn challenges_need_an_interaction_and_a_deposit() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		assert_noop!(challenge(board_id, 0), Error::<Test>::InteractionNotFound);
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+
+		assert_noop!(
+			Popi::challenge_interaction(RuntimeOrigin::signed(150), 1, 2, board_id, 0, 0, 1),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(challenge(board_id, 0));
+		System::assert_last_event(
+			Event::InteractionChallenged {
+				dispute_id: 0,
+				challenger: 3,
+				approver: 1,
+				worker: 2,
+				board_id,
+				task_id: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(3), 50);
+		assert!(Disputes::<Test>::get(0).unwrap().jury.is_empty());
+		assert_noop!(challenge(board_id, 0), Error::<Test>::AlreadyChallenged);
+	});
+}
+
+#[test]
+fn juries_are_drawn_among_the_experts_after_the_challenge() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+		// The parties are left out of the jury
+		for who in [1, 2, 3, 20, 21] {
+			assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), who, FRONTEND, 2));
+		}
+		assert_ok!(challenge(board_id, 0));
+
+		// The randomness of the block of the challenge was determinable when challenging
+		assert_noop!(draw(0), Error::<Test>::JuryNotDrawableYet);
+		System::set_block_number(2);
+		assert_noop!(draw(2), Error::<Test>::DisputeNotFound);
+		assert_noop!(draw(0), Error::<Test>::NotEnoughJurors);
+		// Experts of another experience type can not judge frontend work
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 22, BACKEND, 2));
+		assert_noop!(draw(0), Error::<Test>::NotEnoughJurors);
+		// Neither can experts below `JuryMinLevel`
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 22, FRONTEND, 1));
+		assert_noop!(draw(0), Error::<Test>::NotEnoughJurors);
+
+		// Every account with `JuryMinLevel` is visited, the parties included
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 22, FRONTEND, 2));
+		assert_noop!(
+			Popi::draw_jury(RuntimeOrigin::signed(4), 0, 5),
+			Error::<Test>::TooManyCandidates
+		);
+		let info = Popi::draw_jury(RuntimeOrigin::signed(4), 0, 10).unwrap();
+		// Only the accounts visited are charged for
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::draw_jury(6)));
+		let jury = Disputes::<Test>::get(0).unwrap().jury.into_inner();
+		System::assert_last_event(Event::JuryDrawn { dispute_id: 0, jury: jury.clone() }.into());
+		let mut jury = jury;
+		jury.sort();
+		assert_eq!(jury, vec![20, 21, 22]);
+		assert_noop!(draw(0), Error::<Test>::JuryAlreadyDrawn);
+
+		// Once the voting period is over the jury can not be drawn anymore
+		assert_ok!(challenge(board_id, 1));
+		System::set_block_number(13);
+		assert_noop!(draw(1), Error::<Test>::VotingClosed);
+	});
+}
+
+#[test]
+fn every_expert_is_as_likely_to_be_drawn() {
+	new_test_ext().execute_with(|| {
+		// Experts spread over the levels, so the pool has gaps
+		for (who, level) in (10..20).zip([2, 2, 3, 5, 2, 4, 2, 3, 2, 6]) {
+			assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), who, FRONTEND, level));
+		}
+		let mut seats = [0u32; 10];
+		for draw in 0..300u32 {
+			let seed = BlakeTwo256::hash_of(&draw);
+			let (jury, visited) = Popi::select_jury(seed, FRONTEND, &[], 10).unwrap();
+			assert_eq!(visited, 10);
+			for juror in jury {
+				seats[(juror - 10) as usize] += 1;
+			}
+		}
+		// 90 seats each on average
+		assert!(seats.iter().all(|seats| (60..=120).contains(seats)), "{:?}", seats);
+	});
+}
+
+#[test]
+fn only_the_jury_votes_before_the_deadline() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		seed_jurors();
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(challenge(board_id, 0));
+		// Nobody may vote before the jury is drawn
+		assert_noop!(Popi::vote(RuntimeOrigin::signed(20), 0, true), Error::<Test>::NotAJuror);
+		System::set_block_number(2);
+		assert_ok!(draw(0));
+
+		assert_noop!(
+			Popi::vote(RuntimeOrigin::signed(20), 1, true),
+			Error::<Test>::DisputeNotFound
+		);
+		assert_noop!(Popi::vote(RuntimeOrigin::signed(3), 0, true), Error::<Test>::NotAJuror);
+		assert_ok!(Popi::vote(RuntimeOrigin::signed(20), 0, true));
+		System::assert_last_event(
+			Event::DisputeVoted { dispute_id: 0, juror: 20, guilty: true }.into(),
+		);
+		assert_noop!(Popi::vote(RuntimeOrigin::signed(20), 0, false), Error::<Test>::AlreadyVoted);
+		assert_noop!(
+			Popi::resolve_dispute(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::VotingStillOpen
+		);
+
+		System::set_block_number(12);
+		assert_noop!(Popi::vote(RuntimeOrigin::signed(21), 0, true), Error::<Test>::VotingClosed);
+		// A single vote is a majority once the voting period is over
+		assert_ok!(Popi::resolve_dispute(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(
+			Event::DisputeResolved { dispute_id: 0, verdict: Verdict::Guilty }.into(),
+		);
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 0);
+	});
+}
+
+#[test]
+fn dismissed_challenges_cost_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		seed_jurors();
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+
+		// A tie is not a majority
+		assert_ok!(challenge(board_id, 0));
+		System::set_block_number(2);
+		assert_ok!(draw(0));
+		assert_ok!(Popi::vote(RuntimeOrigin::signed(20), 0, true));
+		assert_ok!(Popi::vote(RuntimeOrigin::signed(21), 0, false));
+		System::set_block_number(12);
+		assert_ok!(Popi::resolve_dispute(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(
+			Event::DisputeResolved { dispute_id: 0, verdict: Verdict::Innocent }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 50);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 15);
+		assert_noop!(challenge(board_id, 0), Error::<Test>::AlreadyChallenged);
+
+		// Without any vote the deposit is returned and the interaction may be challenged again
+		assert_ok!(challenge(board_id, 1));
+		System::set_block_number(23);
+		assert_ok!(Popi::resolve_dispute(RuntimeOrigin::signed(4), 1));
+		System::assert_last_event(
+			Event::DisputeResolved { dispute_id: 1, verdict: Verdict::NoQuorum }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 50);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_ok!(challenge(board_id, 1));
+	});
+}
+
+#[test]
+fn approvals_are_backed_by_a_bond() {
+	new_test_ext().execute_with(|| {
+		BondPerExperience::set(&2);
+		let board_id = create_board(4);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 0, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 0));
+		// An approval worth 10 experience needs a bond of 20
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 0, 1, vec![]),
+			Error::<Test>::BondTooLow
+		);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 19));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 0, 1, vec![]),
+			Error::<Test>::BondTooLow
+		);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(Event::Bonded { who: 1, amount: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 0, 1, vec![]));
+
+		// The approvals of an expert are worth more, so need a larger bond
+		assert_ok!(Popi::seed_expert(RuntimeOrigin::root(), 5, FRONTEND, 3));
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(5), 20));
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 1, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(5), board_id, 1));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(5), 2, board_id, 1, 1, vec![]),
+			Error::<Test>::BondTooLow
+		);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(5), 6));
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(5), 2, board_id, 1, 1, vec![]));
+
+		// The approval locks the bond backing it until it can no longer be challenged, so it can
+		// not be unbonded nor back another approval
+		assert_eq!(Bonds::<Test>::get(1).locks.into_inner(), vec![(20, 41)]);
+		assert_noop!(Popi::unbond(RuntimeOrigin::signed(1), 1), Error::<Test>::BondLocked);
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 2, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 2));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 2, 1, vec![]),
+			Error::<Test>::BondTooLow
+		);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 20));
+		System::set_block_number(11);
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 2, 1, vec![]));
+		// Every approval stays locked until its own challenge and voting periods are over
+		assert_eq!(
+			Bonds::<Test>::get(1),
+			Bond {
+				active: 40,
+				unbonding: 0,
+				unlock_at: 0,
+				locks: vec![(20, 41), (20, 51)].try_into().unwrap(),
+			}
+		);
+		System::set_block_number(41);
+		assert_noop!(Popi::unbond(RuntimeOrigin::signed(1), 21), Error::<Test>::BondLocked);
+		assert_ok!(Popi::unbond(RuntimeOrigin::signed(1), 20));
+		assert_eq!(Bonds::<Test>::get(1).locks.into_inner(), vec![(20, 51)]);
+		System::set_block_number(51);
+
+		// Unbonding funds do not back approvals anymore
+		assert_ok!(Popi::unbond(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 3, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 3));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 3, 1, vec![]),
+			Error::<Test>::BondTooLow
+		);
+		assert_noop!(
+			Popi::bond(RuntimeOrigin::signed(150), 1),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn bond_locks_are_bounded() {
+	new_test_ext().execute_with(|| {
+		BondPerExperience::set(&1);
+		let board_id = create_board(4);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 100));
+		// Every block the approver approves in adds a lock, up to `MaxBondLocks`
+		for task_id in 0..4 {
+			System::set_block_number(task_id as u64 + 1);
+			assert_ok!(approve(1, 2, board_id, task_id, 1));
+		}
+		assert_ok!(Popi::mark_ready(RuntimeOrigin::signed(2), board_id, 4, vec![]));
+		assert_ok!(Popi::pull(RuntimeOrigin::signed(1), board_id, 4));
+		assert_noop!(
+			Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 4, 1, vec![]),
+			Error::<Test>::TooManyBondLocks
+		);
+		// The approvals of the same block share their lock
+		System::set_block_number(4);
+		assert_ok!(Popi::interact(RuntimeOrigin::signed(1), 2, board_id, 4, 1, vec![]));
+		assert_eq!(
+			Bonds::<Test>::get(1).locks.into_inner(),
+			vec![(10, 41), (10, 42), (10, 43), (20, 44)]
+		);
+
+		// Once the first lock is released, there is room for another one
+		System::set_block_number(41);
+		assert_ok!(approve(1, 2, board_id, 5, 1));
+		assert_eq!(
+			Bonds::<Test>::get(1).locks.into_inner(),
+			vec![(10, 42), (10, 43), (20, 44), (10, 81)]
+		);
+	});
+}
+
+#[test]
+fn bonds_are_withdrawn_after_the_unbonding_period() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Popi::withdraw_unbonded(RuntimeOrigin::signed(1)),
+			Error::<Test>::NothingToWithdraw
+		);
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 100));
+		assert_noop!(Popi::unbond(RuntimeOrigin::signed(1), 101), Error::<Test>::InsufficientBond);
+		assert_ok!(Popi::unbond(RuntimeOrigin::signed(1), 60));
+		System::assert_last_event(Event::Unbonding { who: 1, amount: 60, unlock_at: 21 }.into());
+		assert_eq!(
+			Bonds::<Test>::get(1),
+			Bond { active: 40, unbonding: 60, unlock_at: 21, locks: Default::default() }
+		);
+
+		// Unbonding more restarts the period
+		System::set_block_number(11);
+		assert_ok!(Popi::unbond(RuntimeOrigin::signed(1), 10));
+		System::set_block_number(30);
+		assert_noop!(
+			Popi::withdraw_unbonded(RuntimeOrigin::signed(1)),
+			Error::<Test>::StillUnbonding
+		);
+		System::set_block_number(31);
+		assert_ok!(Popi::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::Withdrawn { who: 1, amount: 70 }.into());
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 30);
+
+		// Nothing is left once everything is withdrawn
+		assert_ok!(Popi::unbond(RuntimeOrigin::signed(1), 30));
+		System::set_block_number(51);
+		assert_ok!(Popi::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		assert!(!Bonds::<Test>::contains_key(1));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn bounties_are_paid_to_the_workers_of_completed_tasks() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::fund_bounty(RuntimeOrigin::signed(3), board_id, 1, 100));
+		// Funding again tops the bounty up
+		assert_ok!(Popi::fund_bounty(RuntimeOrigin::signed(3), board_id, 1, 50));
+		System::assert_last_event(
+			Event::BountyFunded { board_id, task_id: 1, funder: 3, amount: 50 }.into(),
+		);
+		assert_eq!(Bounties::<Test>::get(board_id, 1), Some(Bounty { funder: 3, amount: 150 }));
+		// On top of the existential deposit keeping the account alive
+		assert_eq!(Balances::free_balance(Popi::bounty_account()), EXISTENTIAL_DEPOSIT + 150);
+
+		// 2 is credited 10, 5 then 2 and 1 is credited 10 then 5 as the pair keeps repeating
+		for dst_state in 1..5 {
+			let (approver, worker) = if dst_state % 2 == 1 { (1, 2) } else { (2, 1) };
+			assert_ok!(approve(approver, worker, board_id, 1, dst_state));
+		}
+		let contributions = TaskContributions::<Test>::get(board_id, 1)
+			.iter()
+			.map(|contribution| (contribution.granted, contribution.awarded))
+			.collect::<Vec<_>>();
+		assert_eq!(contributions, vec![(10, 10), (10, 10), (10, 5), (10, 5)]);
+		assert_eq!(Balances::free_balance(Popi::bounty_account()), EXISTENTIAL_DEPOSIT + 150);
+		assert_ok!(approve(1, 2, board_id, 1, 5));
+
+		// Every approval was worth 10 regardless of the repetitions, 30 out of 50 and 20 out of 50
+		System::assert_has_event(
+			Event::BountyPaid { board_id, task_id: 1, worker: 2, amount: 90 }.into(),
+		);
+		System::assert_last_event(
+			Event::BountyPaid { board_id, task_id: 1, worker: 1, amount: 60 }.into(),
+		);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + 90);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + 60);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 150);
+		assert_eq!(Balances::free_balance(Popi::bounty_account()), EXISTENTIAL_DEPOSIT);
+		assert!(!Bounties::<Test>::contains_key(board_id, 1));
+		assert!(!TaskContributions::<Test>::contains_key(board_id, 1));
+
+		// Completed tasks can not be funded anymore
+		assert_noop!(
+			Popi::fund_bounty(RuntimeOrigin::signed(3), board_id, 1, 100),
+			Error::<Test>::TaskCompleted
+		);
+	});
+}
+
+#[test]
+fn bounties_are_shared_by_what_the_work_was_worth() {
+	new_test_ext().execute_with(|| {
+		MaxExperiencePerEpoch::set(&Some(12));
+		let board_id = create_board(3);
+		assert_ok!(Popi::fund_bounty(RuntimeOrigin::signed(3), board_id, 1, 100));
+		// 2 already earned 10 within the epoch, so is only credited 2 for its work on the task
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(approve(4, 2, board_id, 1, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 12);
+		assert_ok!(approve(5, 4, board_id, 1, 2));
+		TaskStates::<Test>::insert(board_id, 1, 4);
+		assert_ok!(approve(6, 5, board_id, 1, 5));
+
+		// Each work was worth 10, the last worker gets what rounding left
+		System::assert_has_event(
+			Event::BountyPaid { board_id, task_id: 1, worker: 2, amount: 33 }.into(),
+		);
+		System::assert_has_event(
+			Event::BountyPaid { board_id, task_id: 1, worker: 4, amount: 33 }.into(),
+		);
+		System::assert_last_event(
+			Event::BountyPaid { board_id, task_id: 1, worker: 5, amount: 34 }.into(),
+		);
+		// The task has been completed, nothing goes back to the owner
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 100);
+		assert_eq!(Balances::free_balance(Popi::bounty_account()), EXISTENTIAL_DEPOSIT);
+	});
+}
+
+#[test]
+fn bounties_are_funded_by_the_owner_and_refunded_on_cancellation() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_noop!(
+			Popi::fund_bounty(RuntimeOrigin::signed(1), board_id, 1, 100),
+			Error::<Test>::NotBoardOwner
+		);
+		assert_noop!(
+			Popi::cancel_bounty(RuntimeOrigin::signed(3), board_id, 1),
+			Error::<Test>::BountyNotFound
+		);
+		assert_ok!(Popi::fund_bounty(RuntimeOrigin::signed(3), board_id, 1, 100));
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE - 100);
+
+		// Work done before the cancellation is not paid
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+		assert_noop!(
+			Popi::cancel_bounty(RuntimeOrigin::signed(1), board_id, 1),
+			Error::<Test>::NotBountyFunder
+		);
+		assert_ok!(Popi::cancel_bounty(RuntimeOrigin::signed(3), board_id, 1));
+		System::assert_last_event(
+			Event::BountyRefunded { board_id, task_id: 1, funder: 3, amount: 100 }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert!(!Bounties::<Test>::contains_key(board_id, 1));
+
+		// The task completes without a bounty
+		TaskStates::<Test>::insert(board_id, 1, 4);
+		assert_ok!(approve(2, 1, board_id, 1, 5));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn revoked_work_earns_no_share_of_the_bounty() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(4);
+		seed_jurors();
+		assert_ok!(Popi::fund_bounty(RuntimeOrigin::signed(4), board_id, 0, 100));
+		assert_ok!(Popi::bond(RuntimeOrigin::signed(1), 100));
+		assert_ok!(approve(1, 2, board_id, 0, 1));
+		assert_ok!(approve(5, 1, board_id, 0, 2));
+
+		assert_ok!(challenge(board_id, 0));
+		System::set_block_number(2);
+		assert_ok!(draw(0));
+		for juror in [20, 21, 22] {
+			assert_ok!(Popi::vote(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_eq!(
+			TaskContributions::<Test>::get(board_id, 0).into_inner(),
+			vec![Contribution { dst_state: 2, worker: 1, granted: 10, awarded: 10 }]
+		);
+
+		// 2 is left out, 1 and 5 split the bounty
+		TaskStates::<Test>::insert(board_id, 0, 4);
+		assert_ok!(approve(6, 5, board_id, 0, 5));
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 100 + 50);
+		assert_eq!(Balances::free_balance(5), INITIAL_BALANCE + 50);
+	});
+}
+
+#[test]
+fn epochs_close_on_schedule_and_tally_the_experience_earned() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		// 2 is credited 10 then 5 as the pair repeats, 1 is credited 10
+		for dst_state in 1..4 {
+			let (approver, worker) = if dst_state % 2 == 1 { (1, 2) } else { (2, 1) };
+			assert_ok!(approve(approver, worker, board_id, 1, dst_state));
+		}
+		// Experience that is not earned through interactions is not tallied
+		assert_ok!(Popi::add_user_experience(4, FRONTEND, 50));
+		assert_eq!(EpochExperience::<Test>::get(0, 2), 15);
+		assert_eq!(EpochExperience::<Test>::get(0, 1), 10);
+		assert!(!EpochExperience::<Test>::contains_key(0, 4));
+		assert_eq!(EpochTotals::<Test>::get(0), EpochSummary { experience: 25, contributors: 2 });
+
+		Popi::on_initialize(99);
+		assert_eq!(Popi::current_epoch(), 0);
+		Popi::on_initialize(100);
+		assert_eq!(Popi::current_epoch(), 1);
+		assert_eq!(EpochStart::<Test>::get(), 100);
+		System::assert_last_event(
+			Event::EpochClosed { epoch: 0, experience: 25, contributors: 2, rewards: 0 }.into(),
+		);
+
+		// Closed epochs do not change anymore
+		assert_ok!(approve(2, 1, board_id, 1, 4));
+		assert_eq!(EpochExperience::<Test>::get(0, 1), 10);
+		assert_eq!(EpochExperience::<Test>::get(1, 1), 5);
+		Popi::on_initialize(199);
+		assert_eq!(Popi::current_epoch(), 1);
+		Popi::on_initialize(200);
+		System::assert_last_event(
+			Event::EpochClosed { epoch: 1, experience: 5, contributors: 1, rewards: 0 }.into(),
+		);
+		assert_eq!(Popi::current_epoch(), 2);
+	});
+}
+
+#[test]
+fn experience_earned_within_an_epoch_is_capped() {
+	new_test_ext().execute_with(|| {
+		MaxExperiencePerEpoch::set(&Some(12));
+		let board_id = create_board(3);
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+		assert_ok!(approve(2, 1, board_id, 1, 2));
+		// 3 approves the work of 2 for the first time, yet only 2 are left to earn
+		assert_ok!(approve(3, 2, board_id, 1, 3));
+		System::assert_has_event(
+			Event::InteractionRecorded {
+				approver: 3,
+				worker: 2,
+				board_id,
+				task_id: 1,
+				awarded: 2,
+				repetitions: 0,
+			}
+			.into(),
+		);
+		// The contribution to the task is what the work was worth, apart from what was credited
+		let contribution = TaskContributions::<Test>::get(board_id, 1)[2].clone();
+		assert_eq!((contribution.granted, contribution.awarded), (10, 2));
+		assert_ok!(approve(4, 2, board_id, 2, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 12);
+		assert_eq!(EpochTotals::<Test>::get(0), EpochSummary { experience: 22, contributors: 2 });
+
+		// The cap is lifted by the next epoch
+		Popi::on_initialize(100);
+		assert_ok!(approve(5, 2, board_id, 3, 1));
+		assert_eq!(Popi::get_user_experience(2, FRONTEND).unwrap().experience, 22);
+		assert_eq!(EpochExperience::<Test>::get(1, 2), 10);
+	});
+}
+
+#[test]
+fn rewards_are_shared_by_the_experience_earned_within_the_epoch() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::fund_rewards(RuntimeOrigin::signed(9), 300));
+		System::assert_last_event(Event::RewardsFunded { who: 9, amount: 300 }.into());
+		assert_eq!(Popi::reward_pot(), 300);
+		assert_noop!(
+			Popi::claim_rewards(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::EpochNotClosed
+		);
+
+		// Nobody earned anything, so the pot rolls over
+		Popi::on_initialize(100);
+		System::assert_last_event(
+			Event::EpochClosed { epoch: 0, experience: 0, contributors: 0, rewards: 0 }.into(),
+		);
+		assert_eq!(Popi::reward_pot(), 300);
+
+		// 2 earns 15 and 1 earns 10, the pot is topped up by the inflation
+		InflationPerEpoch::set(&30);
+		for dst_state in 1..4 {
+			let (approver, worker) = if dst_state % 2 == 1 { (1, 2) } else { (2, 1) };
+			assert_ok!(approve(approver, worker, board_id, 1, dst_state));
+		}
+		Popi::on_initialize(200);
+		System::assert_last_event(
+			Event::EpochClosed { epoch: 1, experience: 25, contributors: 2, rewards: 330 }.into(),
+		);
+		assert_eq!(EpochRewards::<Test>::get(1), EpochReward { total: 330, unclaimed: 330 });
+		assert_eq!(Popi::reward_pot(), 0);
+		// The bounty and the reward accounts were each funded with the existential deposit
+		assert_eq!(
+			Balances::total_issuance(),
+			100 * INITIAL_BALANCE + 2 * EXISTENTIAL_DEPOSIT + 30
+		);
+
+		assert_ok!(Popi::claim_rewards(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(Event::RewardsClaimed { who: 2, epoch: 1, amount: 198 }.into());
+		assert_ok!(Popi::claim_rewards(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(Event::RewardsClaimed { who: 1, epoch: 1, amount: 132 }.into());
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + 198);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE + 132);
+		assert_eq!(EpochRewards::<Test>::get(1), EpochReward { total: 330, unclaimed: 0 });
+		assert_eq!(Balances::free_balance(Popi::reward_account()), EXISTENTIAL_DEPOSIT);
+
+		assert_noop!(
+			Popi::claim_rewards(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::RewardsAlreadyClaimed
+		);
+		assert_noop!(Popi::claim_rewards(RuntimeOrigin::signed(3), 1), Error::<Test>::NoRewards);
+		assert_noop!(Popi::claim_rewards(RuntimeOrigin::signed(2), 0), Error::<Test>::NoRewards);
+	});
+}
+
+#[test]
+fn unclaimed_rewards_go_back_to_the_pot_once_expired() {
+	new_test_ext().execute_with(|| {
+		let board_id = create_board(3);
+		assert_ok!(Popi::fund_rewards(RuntimeOrigin::signed(9), 300));
+		assert_ok!(approve(1, 2, board_id, 1, 1));
+		assert_ok!(approve(2, 1, board_id, 1, 2));
+		Popi::on_initialize(100);
+		assert_ok!(Popi::claim_rewards(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::RewardsClaimed { who: 2, epoch: 0, amount: 150 }.into());
+
+		// The rewards may still be claimed while the two next epochs are running
+		Popi::on_initialize(200);
+		assert_eq!(EpochRewards::<Test>::get(0), EpochReward { total: 300, unclaimed: 150 });
+		assert_eq!(Popi::reward_pot(), 0);
+
+		// Then what is left goes back to the pot
+		Popi::on_initialize(300);
+		System::assert_has_event(Event::RewardsExpired { epoch: 0, amount: 150 }.into());
+		assert_eq!(EpochRewards::<Test>::get(0), EpochReward { total: 300, unclaimed: 0 });
+		assert_eq!(Popi::reward_pot(), 150);
+		assert_eq!(Balances::free_balance(Popi::reward_account()), EXISTENTIAL_DEPOSIT + 150);
+		assert_noop!(
+			Popi::claim_rewards(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::RewardsExpired
+		);
+
+		// The entries of the expired epoch are pruned when the blocks have room for it
+		assert!(EpochExperience::<Test>::contains_key(0, 1));
+		assert!(RewardsClaimed::<Test>::contains_key(0, 2));
+		Popi::on_idle(300, Weight::MAX);
+		assert_eq!(EpochExperience::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(RewardsClaimed::<Test>::iter_prefix(0).count(), 0);
+		assert!(!EpochRewards::<Test>::contains_key(0));
+		assert!(!EpochTotals::<Test>::contains_key(0));
+		// The next epoch has not expired yet
+		assert_eq!(crate::EpochSweepCursor::<Test>::get(), 1);
 	});
 }
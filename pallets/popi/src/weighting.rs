@@ -0,0 +1,95 @@
+//! Award weightings used to scale the experience an approval is worth.
+//!
+//! A weighting answers one question: given the base amount of experience of an interaction, how
+//! much does an approval from someone at `approver_level` in the experience type of the column
+//! actually credit. Approvals from established experts should count more than approvals from
+//! fresh accounts, so that farming experience with a swarm of new accounts does not pay.
+//!
+//! A repetition decay then reduces what is left when the same approver keeps approving the
+//! same worker, so that two accounts approving each other endlessly do not pay either.
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use sp_runtime::Percent;
+
+/// Defines the experience an approval is worth depending on the level of the approver
+pub trait AwardWeighting {
+	/// Experience credited for an interaction worth `base`, approved by someone at
+	/// `approver_level`
+	fn weigh(base: u128, approver_level: u32) -> u128;
+}
+
+/// Every approval is worth `base`, whoever gives it
+pub struct FlatWeighting;
+impl AwardWeighting for FlatWeighting {
+	fn weigh(base: u128, _approver_level: u32) -> u128 {
+		base
+	}
+}
+
+/// `Floor` of `base` at level 0, growing linearly up to the whole `base` at `FullLevel`
+///
+/// With `Floor = 20%` and `FullLevel = 4` an approval is worth 20%, 40%, 60%, 80% then 100% of
+/// `base`.
+pub struct LinearWeighting<Floor, FullLevel>(PhantomData<(Floor, FullLevel)>);
+impl<Floor, FullLevel> AwardWeighting for LinearWeighting<Floor, FullLevel>
+where
+	Floor: Get<Percent>,
+	FullLevel: Get<u32>,
+{
+	fn weigh(base: u128, approver_level: u32) -> u128 {
+		let full_level = FullLevel::get();
+		if approver_level >= full_level {
+			return base
+		}
+		let floor = Floor::get().mul_floor(base);
+		let progress = Percent::from_rational(approver_level, full_level);
+		floor.saturating_add(progress.mul_floor(base.saturating_sub(floor)))
+	}
+}
+
+/// `base + base * Bonus * approver_level`
+///
+/// With `Bonus = 10%` an approval is worth `base` at level 0, twice `base` at level 10 and so on.
+pub struct LevelBonusWeighting<Bonus>(PhantomData<Bonus>);
+impl<Bonus: Get<Percent>> AwardWeighting for LevelBonusWeighting<Bonus> {
+	fn weigh(base: u128, approver_level: u32) -> u128 {
+		let bonus = Bonus::get().mul_floor(base).saturating_mul(approver_level as u128);
+		base.saturating_add(bonus)
+	}
+}
+
+/// Defines how much of an award is left once the same approver already approved the same worker
+/// `repetitions` times within the configured window
+pub trait RepetitionDecay {
+	/// What is left of `award` after `repetitions` earlier approvals of the same pair
+	fn decay(award: u128, repetitions: u32) -> u128;
+}
+
+/// Repeated approvals are worth as much as the first one
+pub struct NoDecay;
+impl RepetitionDecay for NoDecay {
+	fn decay(award: u128, _repetitions: u32) -> u128 {
+		award
+	}
+}
+
+/// `award / 2 ^ repetitions`
+///
+/// With an award of 10 the same pair credits 10, 5, 2, 1 then nothing.
+pub struct HalvingDecay;
+impl RepetitionDecay for HalvingDecay {
+	fn decay(award: u128, repetitions: u32) -> u128 {
+		award.checked_shr(repetitions).unwrap_or_default()
+	}
+}
+
+/// `award - award * Step * repetitions`
+///
+/// With `Step = 25%` the same pair credits 100%, 75%, 50%, 25% then nothing.
+pub struct LinearDecay<Step>(PhantomData<Step>);
+impl<Step: Get<Percent>> RepetitionDecay for LinearDecay<Step> {
+	fn decay(award: u128, repetitions: u32) -> u128 {
+		let lost = Step::get().mul_ceil(award).saturating_mul(repetitions as u128);
+		award.saturating_sub(lost)
+	}
+}
@@ -1,30 +1,15 @@
-
-//! Autogenerated weights for pallet_popi
+//! Weights for pallet_popi
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-popi
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_popi
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --execution=wasm
-// --wasm-execution=compiled
-// --output
-// pallets/popi/src/weights.rs
-// --popi
-// ../../.maintain/frame-weight-popi.hbs
+//! THESE WEIGHTS ARE HAND-MAINTAINED PLACEHOLDERS, NOT BENCHMARK RESULTS. They were written after
+//! the shape of the weights the benchmark CLI generates, from the storage every call accesses,
+//! and are updated by hand whenever a call changes. Regenerate them on reference hardware, with
+//! a node built with `--features runtime-benchmarks`, before relying on them:
+//!
+//! ```text
+//! ./target/release/node-popi benchmark pallet --chain dev --pallet pallet_popi \
+//!     --extrinsic '*' --steps=50 --repeat=20 --execution=wasm --wasm-execution=compiled \
+//!     --output pallets/popi/src/weights.rs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -35,57 +20,1077 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_popi.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn interact(e: u32) -> Weight;
+	fn create_board(c: u32) -> Weight;
+	fn update_board(c: u32) -> Weight;
+	fn archive_board() -> Weight;
+	fn mark_ready(e: u32) -> Weight;
+	fn pull() -> Weight;
+	fn seed_expert(l: u32) -> Weight;
+	fn register_experience_type() -> Weight;
+	fn update_experience_type() -> Weight;
+	fn sweep_decay(n: u32) -> Weight;
+	fn sweep_pairs(n: u32) -> Weight;
+	fn sweep_epochs(n: u32) -> Weight;
+	fn challenge_interaction() -> Weight;
+	fn draw_jury(c: u32) -> Weight;
+	fn vote() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn bond() -> Weight;
+	fn unbond() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn fund_bounty() -> Weight;
+	fn cancel_bounty() -> Weight;
+	fn close_epoch() -> Weight;
+	fn fund_rewards() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn unpull() -> Weight;
+	fn release_task() -> Weight;
 }
 
-/// Weights for pallet_popi using the Substrate node and recommended hardware.
+/// Placeholder weights for pallet_popi, see the module documentation.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: popiModule Something (r:0 w:1)
-	/// Proof: popiModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:1)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule ColumnTasks (r:2 w:2)
+	/// Proof: PopiModule ColumnTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:2 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule Interaction (r:1 w:1)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PopiModule WorkerHistory (r:1 w:1)
+	/// Proof: PopiModule WorkerHistory (max_values: None, max_size: Some(2849), added: 5324, mode: MaxEncodedLen)
+	/// Storage: PopiModule ApproverHistory (r:1 w:1)
+	/// Proof: PopiModule ApproverHistory (max_values: None, max_size: Some(2849), added: 5324, mode: MaxEncodedLen)
+	/// Storage: PopiModule InteractionsByBoard (r:0 w:1)
+	/// Proof: PopiModule InteractionsByBoard (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: PopiModule InteractionsByWorker (r:0 w:1)
+	/// Proof: PopiModule InteractionsByWorker (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: PopiModule PendingEvidence (r:1 w:1)
+	/// Proof: PopiModule PendingEvidence (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PopiModule InteractionEvidence (r:0 w:1)
+	/// Proof: PopiModule InteractionEvidence (max_values: None, max_size: Some(1160), added: 3635, mode: MaxEncodedLen)
+	/// Storage: PopiModule PairInteractions (r:1 w:1)
+	/// Proof: PopiModule PairInteractions (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:2 w:1)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskContributions (r:1 w:1)
+	/// Proof: PopiModule TaskContributions (max_values: None, max_size: Some(1025), added: 3500, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bounties (r:1 w:1)
+	/// Proof: PopiModule Bounties (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:18 w:18)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:1)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `e` is `[0, 8]`.
+	fn interact(e: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10451`
+		//  Estimated: `105320`
+		// Minimum execution time: 411_000_000 picoseconds.
+		Weight::from_parts(413_617_000, 105320)
+			// Standard Error: 33_417
+			.saturating_add(Weight::from_parts(618_940, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(39_u64))
+			.saturating_add(T::DbWeight::get().writes(39_u64))
+	}
+	/// Storage: PopiModule ExperienceTypes (r:16 w:0)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: PopiModule NextBoardId (r:1 w:1)
+	/// Proof: PopiModule NextBoardId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule Boards (r:0 w:1)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// The range of component `c` is `[2, 16]`.
+	fn create_board(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `1489 + c * (2553 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_327_000, 1489)
+			// Standard Error: 276_236
+			.saturating_add(Weight::from_parts(2_486_130, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(c.into()))
+	}
+	/// Storage: PopiModule Boards (r:1 w:1)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceTypes (r:16 w:0)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: PopiModule ColumnTasks (r:14 w:0)
+	/// Proof: PopiModule ColumnTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// The range of component `c` is `[2, 16]`.
+	fn update_board(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `39786 + c * (2553 ±0)`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(27_335_000, 39786)
+			// Standard Error: 274_612
+			.saturating_add(Weight::from_parts(2_471_508, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(c.into()))
+	}
+	/// Storage: PopiModule Boards (r:1 w:1)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	fn archive_board() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `4744`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_120_000, 4744)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:0)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule PendingEvidence (r:0 w:1)
+	/// Proof: PopiModule PendingEvidence (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// The range of component `e` is `[0, 8]`.
+	fn mark_ready(e: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `9827`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_514_000, 9827)
+			// Standard Error: 18_936
+			.saturating_add(Weight::from_parts(298_771, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:0)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:0)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	fn pull() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `12422`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(23_512_000, 12422)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: popiModule Something (r:1 w:1)
-	/// Proof: popiModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: PopiModule ExperienceTypes (r:1 w:0)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1 w:1)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 100]`.
+	fn seed_expert(l: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `8669`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(25_372_000, 8669)
+			// Standard Error: 42_490
+			.saturating_add(Weight::from_parts(382_417, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PopiModule NextExperienceTypeId (r:1 w:1)
+	/// Proof: PopiModule NextExperienceTypeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceTypes (r:0 w:1)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	fn register_experience_type() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
+		//  Measured:  `6`
 		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_208_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule ExperienceTypes (r:1 w:1)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	fn update_experience_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `151`
+		//  Estimated: `3543`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_017_000, 3543)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PopiModule DecaySweepCursor (r:1 w:1)
+	/// Proof: PopiModule DecaySweepCursor (max_values: Some(1), max_size: Some(36), added: 531, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:101 w:100)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:100 w:100)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:200)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_decay(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156 + n * (146 ±0)`
+		//  Estimated: `1521 + n * (5126 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_412_000, 1521)
+			// Standard Error: 21_704
+			.saturating_add(Weight::from_parts(14_260_318, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5126).saturating_mul(n.into()))
+	}
+	/// Storage: PopiModule PairSweepCursor (r:1 w:1)
+	/// Proof: PopiModule PairSweepCursor (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: PopiModule PairInteractions (r:101 w:100)
+	/// Proof: PopiModule PairInteractions (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_pairs(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (129 ±0)`
+		//  Estimated: `1549 + n * (2604 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_208_000, 1549)
+			// Standard Error: 9_310
+			.saturating_add(Weight::from_parts(6_731_460, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2604).saturating_mul(n.into()))
+	}
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochSweepCursor (r:1 w:1)
+	/// Proof: PopiModule EpochSweepCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:0 w:100)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochRewards (r:0 w:1)
+	/// Proof: PopiModule EpochRewards (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:0 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_epochs(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + n * (76 ±0)`
+		//  Estimated: `1489 + n * (2551 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_154_000, 1489)
+			// Standard Error: 6_918
+			.saturating_add(Weight::from_parts(2_874_112, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2551).saturating_mul(n.into()))
+	}
+	/// Storage: PopiModule Interaction (r:1 w:0)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: PopiModule DisputedInteractions (r:1 w:1)
+	/// Proof: PopiModule DisputedInteractions (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: PopiModule NextDisputeId (r:1 w:1)
+	/// Proof: PopiModule NextDisputeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Disputes (r:0 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	fn challenge_interaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `8332`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(48_210_000, 8332)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PopiModule Disputes (r:1 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Proof: RandomnessCollectiveFlip RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:1000 w:0)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1000 w:0)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1000 w:0)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `c` is `[8, 1000]`.
+	fn draw_jury(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204 + c * (322 ±0)`
+		//  Estimated: `6228 + c * (7721 ±0)`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(31_472_000, 6228)
+			// Standard Error: 9_215
+			.saturating_add(Weight::from_parts(6_108_337, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 7721).saturating_mul(c.into()))
+	}
+	/// Storage: PopiModule Disputes (r:1 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Interaction (r:1 w:0)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1 w:0)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule DisputedInteractions (r:0 w:1)
+	/// Proof: PopiModule DisputedInteractions (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskContributions (r:1 w:1)
+	/// Proof: PopiModule TaskContributions (max_values: None, max_size: Some(1025), added: 3500, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochStart (r:1 w:0)
+	/// Proof: PopiModule EpochStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:1)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1253`
+		//  Estimated: `18079`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(65_219_000, 18079)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: PopiModule Disputes (r:1 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Interaction (r:1 w:0)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1 w:0)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule DisputedInteractions (r:0 w:1)
+	/// Proof: PopiModule DisputedInteractions (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskContributions (r:1 w:1)
+	/// Proof: PopiModule TaskContributions (max_values: None, max_size: Some(1025), added: 3500, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochStart (r:1 w:0)
+	/// Proof: PopiModule EpochStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:1)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1247`
+		//  Estimated: `18079`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(62_402_000, 18079)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	fn bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `7152`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(31_842_000, 7152)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	fn unbond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `3549`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_313_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `7152`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(30_565_000, 7152)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:0)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bounties (r:1 w:1)
+	/// Proof: PopiModule Bounties (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn fund_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1518`
+		//  Estimated: `14026`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_218_000, 14026)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PopiModule Bounties (r:1 w:1)
+	/// Proof: PopiModule Bounties (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `332`
+		//  Estimated: `7761`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(37_641_000, 7761)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PopiModule EpochStart (r:1 w:1)
+	/// Proof: PopiModule EpochStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:1)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:0)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	/// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PopiModule RewardPot (r:1 w:1)
+	/// Proof: PopiModule RewardPot (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochRewards (r:1 w:2)
+	/// Proof: PopiModule EpochRewards (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn close_epoch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `364`
+		//  Estimated: `10619`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(29_514_000, 10619)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule RewardPot (r:1 w:1)
+	/// Proof: PopiModule RewardPot (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn fund_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212`
+		//  Estimated: `6707`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(36_910_000, 6707)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule RewardsClaimed (r:1 w:1)
+	/// Proof: PopiModule RewardsClaimed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:0)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:0)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochRewards (r:1 w:1)
+	/// Proof: PopiModule EpochRewards (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `561`
+		//  Estimated: `18314`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(47_301_000, 18314)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn unpull() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3562`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_407_000, 3562)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule PendingEvidence (r:0 w:1)
+	/// Proof: PopiModule PendingEvidence (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn release_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `8306`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_915_000, 8306)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: popiModule Something (r:0 w:1)
-	/// Proof: popiModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:1)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule ColumnTasks (r:2 w:2)
+	/// Proof: PopiModule ColumnTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:2 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule Interaction (r:1 w:1)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PopiModule WorkerHistory (r:1 w:1)
+	/// Proof: PopiModule WorkerHistory (max_values: None, max_size: Some(2849), added: 5324, mode: MaxEncodedLen)
+	/// Storage: PopiModule ApproverHistory (r:1 w:1)
+	/// Proof: PopiModule ApproverHistory (max_values: None, max_size: Some(2849), added: 5324, mode: MaxEncodedLen)
+	/// Storage: PopiModule InteractionsByBoard (r:0 w:1)
+	/// Proof: PopiModule InteractionsByBoard (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: PopiModule InteractionsByWorker (r:0 w:1)
+	/// Proof: PopiModule InteractionsByWorker (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: PopiModule PendingEvidence (r:1 w:1)
+	/// Proof: PopiModule PendingEvidence (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// Storage: PopiModule InteractionEvidence (r:0 w:1)
+	/// Proof: PopiModule InteractionEvidence (max_values: None, max_size: Some(1160), added: 3635, mode: MaxEncodedLen)
+	/// Storage: PopiModule PairInteractions (r:1 w:1)
+	/// Proof: PopiModule PairInteractions (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:2 w:1)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskContributions (r:1 w:1)
+	/// Proof: PopiModule TaskContributions (max_values: None, max_size: Some(1025), added: 3500, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bounties (r:1 w:1)
+	/// Proof: PopiModule Bounties (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:18 w:18)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:1)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `e` is `[0, 8]`.
+	fn interact(e: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10451`
+		//  Estimated: `105320`
+		// Minimum execution time: 411_000_000 picoseconds.
+		Weight::from_parts(413_617_000, 105320)
+			// Standard Error: 33_417
+			.saturating_add(Weight::from_parts(618_940, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(39_u64))
+			.saturating_add(RocksDbWeight::get().writes(39_u64))
+	}
+	/// Storage: PopiModule ExperienceTypes (r:16 w:0)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: PopiModule NextBoardId (r:1 w:1)
+	/// Proof: PopiModule NextBoardId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule Boards (r:0 w:1)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// The range of component `c` is `[2, 16]`.
+	fn create_board(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `1489 + c * (2553 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_327_000, 1489)
+			// Standard Error: 276_236
+			.saturating_add(Weight::from_parts(2_486_130, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(c.into()))
+	}
+	/// Storage: PopiModule Boards (r:1 w:1)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceTypes (r:16 w:0)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: PopiModule ColumnTasks (r:14 w:0)
+	/// Proof: PopiModule ColumnTasks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// The range of component `c` is `[2, 16]`.
+	fn update_board(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `39786 + c * (2553 ±0)`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(27_335_000, 39786)
+			// Standard Error: 274_612
+			.saturating_add(Weight::from_parts(2_471_508, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(c.into()))
+	}
+	/// Storage: PopiModule Boards (r:1 w:1)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	fn archive_board() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `4744`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_120_000, 4744)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:0)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule PendingEvidence (r:0 w:1)
+	/// Proof: PopiModule PendingEvidence (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	/// The range of component `e` is `[0, 8]`.
+	fn mark_ready(e: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `9827`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_514_000, 9827)
+			// Standard Error: 18_936
+			.saturating_add(Weight::from_parts(298_771, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:0)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:0)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	fn pull() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `12422`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(23_512_000, 12422)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: popiModule Something (r:1 w:1)
-	/// Proof: popiModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: PopiModule ExperienceTypes (r:1 w:0)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1 w:1)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 100]`.
+	fn seed_expert(l: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
+		//  Measured:  `180`
+		//  Estimated: `8669`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(25_372_000, 8669)
+			// Standard Error: 42_490
+			.saturating_add(Weight::from_parts(382_417, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PopiModule NextExperienceTypeId (r:1 w:1)
+	/// Proof: PopiModule NextExperienceTypeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceTypes (r:0 w:1)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	fn register_experience_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
 		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_208_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule ExperienceTypes (r:1 w:1)
+	/// Proof: PopiModule ExperienceTypes (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	fn update_experience_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `151`
+		//  Estimated: `3543`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_017_000, 3543)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: PopiModule DecaySweepCursor (r:1 w:1)
+	/// Proof: PopiModule DecaySweepCursor (max_values: Some(1), max_size: Some(36), added: 531, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:101 w:100)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:100 w:100)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:200)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_decay(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156 + n * (146 ±0)`
+		//  Estimated: `1521 + n * (5126 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_412_000, 1521)
+			// Standard Error: 21_704
+			.saturating_add(Weight::from_parts(14_260_318, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5126).saturating_mul(n.into()))
+	}
+	/// Storage: PopiModule PairSweepCursor (r:1 w:1)
+	/// Proof: PopiModule PairSweepCursor (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: PopiModule PairInteractions (r:101 w:100)
+	/// Proof: PopiModule PairInteractions (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_pairs(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (129 ±0)`
+		//  Estimated: `1549 + n * (2604 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_208_000, 1549)
+			// Standard Error: 9_310
+			.saturating_add(Weight::from_parts(6_731_460, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2604).saturating_mul(n.into()))
+	}
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochSweepCursor (r:1 w:1)
+	/// Proof: PopiModule EpochSweepCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:0 w:100)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochRewards (r:0 w:1)
+	/// Proof: PopiModule EpochRewards (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:0 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 100]`.
+	fn sweep_epochs(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + n * (76 ±0)`
+		//  Estimated: `1489 + n * (2551 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_154_000, 1489)
+			// Standard Error: 6_918
+			.saturating_add(Weight::from_parts(2_874_112, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2551).saturating_mul(n.into()))
+	}
+	/// Storage: PopiModule Interaction (r:1 w:0)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: PopiModule DisputedInteractions (r:1 w:1)
+	/// Proof: PopiModule DisputedInteractions (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: PopiModule NextDisputeId (r:1 w:1)
+	/// Proof: PopiModule NextDisputeId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Disputes (r:0 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	fn challenge_interaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `8332`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(48_210_000, 8332)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PopiModule Disputes (r:1 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Proof: RandomnessCollectiveFlip RandomMaterial (max_values: Some(1), max_size: Some(2594), added: 3089, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:1000 w:0)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1000 w:0)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1000 w:0)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// The range of component `c` is `[8, 1000]`.
+	fn draw_jury(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204 + c * (322 ±0)`
+		//  Estimated: `6228 + c * (7721 ±0)`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(31_472_000, 6228)
+			// Standard Error: 9_215
+			.saturating_add(Weight::from_parts(6_108_337, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 7721).saturating_mul(c.into()))
+	}
+	/// Storage: PopiModule Disputes (r:1 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Interaction (r:1 w:0)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1 w:0)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule DisputedInteractions (r:0 w:1)
+	/// Proof: PopiModule DisputedInteractions (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskContributions (r:1 w:1)
+	/// Proof: PopiModule TaskContributions (max_values: None, max_size: Some(1025), added: 3500, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochStart (r:1 w:0)
+	/// Proof: PopiModule EpochStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:1)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1253`
+		//  Estimated: `18079`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(65_219_000, 18079)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: PopiModule Disputes (r:1 w:1)
+	/// Proof: PopiModule Disputes (max_values: None, max_size: Some(664), added: 3139, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Interaction (r:1 w:0)
+	/// Proof: PopiModule Interaction (max_values: None, max_size: Some(170), added: 2645, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceStorage (r:1 w:1)
+	/// Proof: PopiModule ExperienceStorage (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// Storage: PopiModule ExperienceDecayedAt (r:1 w:0)
+	/// Proof: PopiModule ExperienceDecayedAt (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: PopiModule AccountsByLevel (r:0 w:2)
+	/// Proof: PopiModule AccountsByLevel (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: PopiModule DisputedInteractions (r:0 w:1)
+	/// Proof: PopiModule DisputedInteractions (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskContributions (r:1 w:1)
+	/// Proof: PopiModule TaskContributions (max_values: None, max_size: Some(1025), added: 3500, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochStart (r:1 w:0)
+	/// Proof: PopiModule EpochStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:1)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:1)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1247`
+		//  Estimated: `18079`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(62_402_000, 18079)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	fn bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `7152`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(31_842_000, 7152)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	fn unbond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `3549`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(14_313_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PopiModule Bonds (r:1 w:1)
+	/// Proof: PopiModule Bonds (max_values: None, max_size: Some(2646), added: 5121, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `7152`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(30_565_000, 7152)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule TaskStates (r:1 w:0)
+	/// Proof: PopiModule TaskStates (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: PopiModule Bounties (r:1 w:1)
+	/// Proof: PopiModule Bounties (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn fund_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1518`
+		//  Estimated: `14026`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_218_000, 14026)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PopiModule Bounties (r:1 w:1)
+	/// Proof: PopiModule Bounties (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `332`
+		//  Estimated: `7761`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(37_641_000, 7761)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PopiModule EpochStart (r:1 w:1)
+	/// Proof: PopiModule EpochStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule CurrentEpoch (r:1 w:1)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:0)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	/// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PopiModule RewardPot (r:1 w:1)
+	/// Proof: PopiModule RewardPot (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochRewards (r:1 w:2)
+	/// Proof: PopiModule EpochRewards (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn close_epoch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `364`
+		//  Estimated: `10619`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(29_514_000, 10619)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PopiModule RewardPot (r:1 w:1)
+	/// Proof: PopiModule RewardPot (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn fund_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212`
+		//  Estimated: `6707`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(36_910_000, 6707)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PopiModule CurrentEpoch (r:1 w:0)
+	/// Proof: PopiModule CurrentEpoch (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PopiModule RewardsClaimed (r:1 w:1)
+	/// Proof: PopiModule RewardsClaimed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochExperience (r:1 w:0)
+	/// Proof: PopiModule EpochExperience (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochTotals (r:1 w:0)
+	/// Proof: PopiModule EpochTotals (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: PopiModule EpochRewards (r:1 w:1)
+	/// Proof: PopiModule EpochRewards (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `561`
+		//  Estimated: `18314`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(47_301_000, 18314)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn unpull() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3562`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_407_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PopiModule Boards (r:1 w:0)
+	/// Proof: PopiModule Boards (max_values: None, max_size: Some(1279), added: 3754, mode: MaxEncodedLen)
+	/// Storage: PopiModule Assignments (r:1 w:1)
+	/// Proof: PopiModule Assignments (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PopiModule PendingEvidence (r:0 w:1)
+	/// Proof: PopiModule PendingEvidence (max_values: None, max_size: Some(561), added: 3036, mode: MaxEncodedLen)
+	fn release_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `8306`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_915_000, 8306)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -51,15 +51,16 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
 
 /// Import the popi pallet.
 pub use pallet_popi;
@@ -119,10 +120,11 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped whenever the encoding of the calls changes, e.g. the calls of pallet-popi
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -283,13 +285,63 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+	/// Share of an interaction a fresh account can award, experts at level 4 award all of it
+	pub const ApprovalWeightFloor: Percent = Percent::from_percent(20);
+	/// Inactive contributors lose half of their experience every six months
+	pub const ExperienceHalfLife: Option<BlockNumber> = Some(180 * DAYS);
+	/// Reserved from whoever challenges an interaction, lost if the approver is found innocent
+	pub const ChallengeDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	/// Bonded by approvers for every point of experience their approvals grant
+	pub const BondPerExperience: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	/// The task bounties and the rewards are held by the accounts derived from it
+	pub const PopiPalletId: PalletId = PalletId(*b"py/popi_");
+	/// Nobody earns more than a hundred full approvals within a sprint
+	pub const MaxExperiencePerEpoch: Option<u128> = Some(1_000);
+	/// Minted into the reward pot of the contributors at the end of every sprint
+	pub const InflationPerEpoch: Balance = 10_000 * EXISTENTIAL_DEPOSIT;
+}
+
+// NOTE: The collective flip is predictable and can be influenced by block authors, who could
+// then pick the juries of pallet-popi. It only fits test and development chains, a production
+// runtime must provide a secure source of randomness such as BABE.
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
 /// Configure the pallet-popi in pallets/popi.
 impl pallet_popi::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_popi::weights::SubstrateWeight<Runtime>;
-	type BaseExperience = ConstU128<100>;
-	type LevelDifficulty = ConstU32<10>;
-	type DifficultyMultiplier = ConstU32<2>;
+	type LevelCurve = pallet_popi::ExponentialCurve<ConstU128<100>, ConstU32<2>, ConstU32<1>>;
+	type MaxLevel = ConstU32<100>;
+	type ExperiencePerInteraction = ConstU128<10>;
+	type AwardWeighting = pallet_popi::LinearWeighting<ApprovalWeightFloor, ConstU32<4>>;
+	type RepetitionDecay = pallet_popi::HalvingDecay;
+	type PairWindow = ConstU32<{ 7 * DAYS }>;
+	type MaxPairRepetitions = ConstU32<8>;
+	type MaxNameLength = ConstU32<64>;
+	type MaxColumns = ConstU32<16>;
+	type ExperienceTypeAdminOrigin = EnsureRoot<AccountId>;
+	type MaxPositiveUserInteractions = ConstU32<50>;
+	type Time = Timestamp;
+	type MaxPageSize = ConstU32<100>;
+	type MaxEvidence = ConstU32<8>;
+	type ExperienceHalfLife = ExperienceHalfLife;
+	type Currency = Balances;
+	type BondPerExperience = BondPerExperience;
+	type UnbondingPeriod = ConstU32<{ 7 * DAYS }>;
+	type Randomness = RandomnessCollectiveFlip;
+	type Slashed = ();
+	type ChallengeDeposit = ChallengeDeposit;
+	type ChallengePeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxBondLocks = ConstU32<128>;
+	type JurySize = ConstU32<5>;
+	type JuryMinLevel = ConstU32<3>;
+	type VotingPeriod = ConstU32<{ 3 * DAYS }>;
+	type PalletId = PopiPalletId;
+	type EpochLength = ConstU32<{ 14 * DAYS }>;
+	type MaxExperiencePerEpoch = MaxExperiencePerEpoch;
+	type InflationPerEpoch = InflationPerEpoch;
+	type RewardClaimEpochs = ConstU32<6>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -309,6 +361,8 @@ construct_runtime!(
 		Sudo: pallet_sudo,
 		// Include the custom logic from the pallet-popi in the runtime.
 		PopiModule: pallet_popi,
+		// Appended so that the indices of the pallets above, part of the encoded calls, are kept.
+		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
 	}
 );
 
@@ -342,8 +396,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations run on the next runtime upgrade
+type Migrations = (pallet_popi::migrations::v1::MigrateToV1<Runtime>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -504,6 +562,65 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_popi_runtime_api::PopiApi<Block, AccountId> for Runtime {
+		fn user_experience(
+			who: AccountId,
+			exp_type: pallet_popi::ExperienceTypeId,
+		) -> Option<pallet_popi::ExperienceInfo> {
+			PopiModule::user_experience(who, exp_type)
+		}
+		fn user_profile(
+			who: AccountId,
+		) -> Vec<(pallet_popi::ExperienceTypeId, pallet_popi::ExperienceInfo)> {
+			PopiModule::user_profile(who)
+		}
+		fn level_thresholds() -> Vec<u128> {
+			PopiModule::level_thresholds()
+		}
+		fn interaction_exists(
+			approver: AccountId,
+			worker: AccountId,
+			board_id: pallet_popi::BoardId,
+			task_id: pallet_popi::TaskId,
+			src_state: pallet_popi::ColumnIndex,
+			dst_state: pallet_popi::ColumnIndex,
+		) -> bool {
+			PopiModule::interaction_exists(approver, worker, board_id, task_id, src_state, dst_state)
+		}
+		fn interaction_evidence(
+			approver: AccountId,
+			worker: AccountId,
+			board_id: pallet_popi::BoardId,
+			task_id: pallet_popi::TaskId,
+			src_state: pallet_popi::ColumnIndex,
+			dst_state: pallet_popi::ColumnIndex,
+		) -> Option<pallet_popi::EvidenceInfo> {
+			PopiModule::interaction_evidence(approver, worker, board_id, task_id, src_state, dst_state)
+		}
+		fn interactions_by_board(
+			board_id: pallet_popi::BoardId,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> pallet_popi::InteractionPage<AccountId> {
+			PopiModule::interactions_by_board(board_id, cursor, limit)
+		}
+		fn interactions_by_worker(
+			worker: AccountId,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> pallet_popi::InteractionPage<AccountId> {
+			PopiModule::interactions_by_worker(worker, cursor, limit)
+		}
+		fn accounts_by_level(
+			exp_type: pallet_popi::ExperienceTypeId,
+			min_level: u32,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> pallet_popi::LevelPage<AccountId> {
+			PopiModule::accounts_by_level(exp_type, min_level, cursor, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{